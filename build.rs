@@ -0,0 +1,39 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+// Embeds every Markdown file in `posts/` and every message catalog in
+// `locales/` into the binary, so publishing a post or adding a language is
+// just adding a file there.
+fn main() {
+    embed("posts", "md", "SOURCES", "posts.rs");
+    embed("locales", "txt", "CATALOGS", "locales.rs");
+}
+
+// Writes `const <name>: &[(&str, &str)]` with (file stem, file contents)
+// for each file in `dir` with extension `ext`, sorted by file name
+fn embed(dir: &str, ext: &str, name: &str, out_file: &str) {
+    println!("cargo:rerun-if-changed={}", dir);
+
+    let mut paths: Vec<_> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|e| e == ext))
+                .collect()
+        })
+        .unwrap_or_default();
+    paths.sort();
+
+    let mut code = format!("pub const {}: &[(&str, &str)] = &[\n", name);
+    for path in paths {
+        let stem = path.file_stem().unwrap().to_string_lossy().into_owned();
+        let full_path = fs::canonicalize(&path).unwrap();
+        code.push_str(&format!("    ({:?}, include_str!({:?})),\n", stem, full_path.display().to_string()));
+    }
+    code.push_str("];\n");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join(out_file), code).unwrap();
+}
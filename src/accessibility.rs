@@ -0,0 +1,357 @@
+use wasm_bindgen::JsCast;
+use web_sys::{Document, Element, Event, HtmlElement};
+
+use crate::state::Msg;
+
+// What a screen says, as opposed to how it looks. The terminal grid is a
+// wall of box-drawing characters to a screen reader, so every screen also
+// describes itself and `Mirror` keeps an off-screen copy of that in the DOM
+// for assistive tech to read and operate.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Description {
+    title: String,
+    nodes: Vec<Node>,
+    status: String,     // Read out whenever it changes
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Node {
+    Heading(String),
+    Text(String),
+    Control(Control),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Role {
+    Button,
+    TextBox { multiline: bool },
+}
+
+// Something on screen that can be focused or activated, mapped to the
+// message the terminal widget would send
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Control {
+    role: Role,
+    label: String,
+    value: String,
+    hint: Option<String>,
+    action: Msg,
+    on_focus: Option<Msg>,  // Moves the terminal's focus along with the mirror's
+    focused: bool,
+    disabled: bool,
+    invalid: bool,
+}
+
+impl Description {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn heading(mut self, text: impl Into<String>) -> Self {
+        self.nodes.push(Node::Heading(text.into()));
+        self
+    }
+
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.nodes.push(Node::Text(text.into()));
+        self
+    }
+
+    pub fn control(mut self, control: Control) -> Self {
+        self.nodes.push(Node::Control(control));
+        self
+    }
+
+    pub fn status(mut self, status: impl Into<String>) -> Self {
+        self.status = status.into();
+        self
+    }
+
+    fn focused(&self) -> Option<usize> {
+        self.nodes
+            .iter()
+            .position(|node| matches!(node, Node::Control(control) if control.focused))
+    }
+
+    // What has focus, as the debug overlay names it
+    pub fn focused_label(&self) -> Option<&str> {
+        self.nodes.iter().find_map(|node| match node {
+            Node::Control(control) if control.focused => Some(control.label.as_str()),
+            _ => None,
+        })
+    }
+}
+
+impl Control {
+    pub fn button(label: impl Into<String>, action: Msg) -> Self {
+        Self::new(Role::Button, label.into(), action)
+    }
+
+    pub fn text_box(label: impl Into<String>, value: impl Into<String>, action: Msg) -> Self {
+        let mut control = Self::new(Role::TextBox { multiline: false }, label.into(), action);
+        control.value = value.into();
+        control
+    }
+
+    fn new(role: Role, label: String, action: Msg) -> Self {
+        Self {
+            role,
+            label,
+            value: String::new(),
+            hint: None,
+            action,
+            on_focus: None,
+            focused: false,
+            disabled: false,
+            invalid: false,
+        }
+    }
+
+    pub fn hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    pub fn multiline(mut self, multiline: bool) -> Self {
+        if let Role::TextBox { .. } = self.role {
+            self.role = Role::TextBox { multiline };
+        }
+        self
+    }
+
+    // Takes the place of the hint while there is one
+    pub fn error(mut self, error: Option<&str>) -> Self {
+        if let Some(error) = error {
+            self.hint = Some(error.to_string());
+            self.invalid = true;
+        }
+        self
+    }
+
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    pub fn on_focus(mut self, msg: Msg) -> Self {
+        self.on_focus = Some(msg);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl Node {
+    // Nodes with the same tag can be updated in place
+    fn tag(&self) -> &'static str {
+        match self {
+            Node::Heading(_) => "h2",
+            Node::Text(_) => "p",
+            Node::Control(Control { role: Role::Button, .. }) => "button",
+            Node::Control(Control { role: Role::TextBox { .. }, .. }) => "div",
+        }
+    }
+
+    fn create(&self, document: &Document) -> Option<Element> {
+        let element = document.create_element(self.tag()).ok()?;
+
+        if let Node::Control(control) = self {
+            match control.role {
+                Role::Button => {
+                    let _ = element.set_attribute("type", "button");
+                }
+                // Typing reaches the form through the screen's key handler,
+                // so this only has to be in the Tab order and say what it holds
+                Role::TextBox { .. } => {
+                    let _ = element.set_attribute("role", "textbox");
+                    let _ = element.set_attribute("tabindex", "0");
+                }
+            }
+        }
+
+        Some(element)
+    }
+
+    fn apply(&self, element: &Element, index: usize) {
+        match self {
+            Node::Heading(text) | Node::Text(text) => element.set_text_content(Some(text)),
+            Node::Control(control) => {
+                let _ = element.set_attribute("data-action", &index.to_string());
+                set_flag(element, "aria-disabled", control.disabled);
+                set_flag(element, "aria-invalid", control.invalid);
+                set_or_remove(element, "title", control.hint.as_deref());
+
+                match control.role {
+                    Role::Button => element.set_text_content(Some(&control.label)),
+                    Role::TextBox { multiline } => {
+                        let _ = element.set_attribute("aria-label", &control.label);
+                        set_flag(element, "aria-multiline", multiline);
+                        element.set_text_content(Some(&control.value));
+                    }
+                }
+            }
+        }
+    }
+}
+
+// The off-screen elements, created once for the page
+struct Dom {
+    root: Element,
+    title: Element,
+    body: Element,
+    live: Element,
+}
+
+// Keeps the DOM copy of the current screen's description in step with the
+// terminal. Elements are updated in place where possible so assistive tech
+// keeps its place, and focus follows the focused terminal widget.
+#[derive(Default)]
+pub struct Mirror {
+    dom: Option<Dom>,
+    elements: Vec<Element>,     // One per node of `shown`
+    shown: Description,
+}
+
+impl Mirror {
+    pub fn new() -> Self {
+        let dom = Self::create_dom();
+        if dom.is_none() {
+            log::warn!("Failed to create the screen reader mirror");
+        }
+
+        Self {
+            dom,
+            ..Self::default()
+        }
+    }
+
+    // Everything already on the page is the terminal, which is hidden from
+    // assistive tech in favor of the mirror
+    fn create_dom() -> Option<Dom> {
+        let document = web_sys::window()?.document()?;
+        let page = document.body()?;
+
+        let terminal = document.query_selector_all("body > *").ok()?;
+        for index in 0..terminal.length() {
+            if let Some(element) = terminal.item(index).and_then(|node| node.dyn_into::<Element>().ok()) {
+                let _ = element.set_attribute("aria-hidden", "true");
+            }
+        }
+
+        let root = document.create_element("div").ok()?;
+        root.set_id("screen-reader");
+
+        let title = document.create_element("h1").ok()?;
+        title.set_attribute("tabindex", "-1").ok()?;
+
+        let body = document.create_element("div").ok()?;
+
+        let live = document.create_element("div").ok()?;
+        live.set_attribute("role", "status").ok()?;
+        live.set_attribute("aria-live", "polite").ok()?;
+
+        root.append_child(&title).ok()?;
+        root.append_child(&body).ok()?;
+        root.append_child(&live).ok()?;
+        page.append_child(&root).ok()?;
+
+        Some(Dom { root, title, body, live })
+    }
+
+    // Where clicks on the mirror's controls arrive
+    pub fn root(&self) -> Option<Element> {
+        self.dom.as_ref().map(|dom| dom.root.clone())
+    }
+
+    pub fn sync(&mut self, description: Description) {
+        if description == self.shown {
+            return;
+        }
+
+        let Some(dom) = &self.dom else {
+            return;
+        };
+
+        let new_screen = description.title != self.shown.title;
+        let same_shape = description.nodes.len() == self.shown.nodes.len()
+            && description.nodes.iter().zip(&self.shown.nodes).all(|(a, b)| a.tag() == b.tag());
+        let rebuild = new_screen || !same_shape;
+
+        if rebuild {
+            let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+                return;
+            };
+
+            dom.body.set_text_content(None);
+            self.elements = description.nodes.iter().filter_map(|node| node.create(&document)).collect();
+            for element in &self.elements {
+                let _ = dom.body.append_child(element);
+            }
+        }
+
+        for (index, (node, element)) in description.nodes.iter().zip(&self.elements).enumerate() {
+            if rebuild || self.shown.nodes.get(index) != Some(node) {
+                node.apply(element, index);
+            }
+        }
+
+        if new_screen {
+            dom.title.set_text_content(Some(&description.title));
+        }
+        if description.status != self.shown.status {
+            dom.live.set_text_content(Some(&description.status));
+        }
+
+        // A new screen is announced by its title, unless something on it
+        // already has focus
+        let focused = description.focused();
+        let target = match focused {
+            Some(index) if rebuild || focused != self.shown.focused() => self.elements.get(index),
+            None if new_screen => Some(&dom.title),
+            _ => None,
+        };
+        if let Some(element) = target.and_then(|element| element.dyn_ref::<HtmlElement>()) {
+            let _ = element.focus();
+        }
+
+        self.shown = description;
+    }
+
+    // The message for a click inside the mirror, if it hit a control
+    pub fn action_for(&self, event: &Event) -> Option<&Msg> {
+        self.control_for(event).filter(|control| !control.disabled).map(|control| &control.action)
+    }
+
+    // The message for focus landing on a control inside the mirror
+    pub fn focus_for(&self, event: &Event) -> Option<&Msg> {
+        self.control_for(event)?.on_focus.as_ref()
+    }
+
+    fn control_for(&self, event: &Event) -> Option<&Control> {
+        let target = event.target()?.dyn_into::<Element>().ok()?;
+        let control = target.closest("[data-action]").ok()??;
+        let index: usize = control.get_attribute("data-action")?.parse().ok()?;
+
+        match self.shown.nodes.get(index)? {
+            Node::Control(control) => Some(control),
+            _ => None,
+        }
+    }
+}
+
+fn set_flag(element: &Element, name: &str, on: bool) {
+    set_or_remove(element, name, on.then_some("true"));
+}
+
+fn set_or_remove(element: &Element, name: &str, value: Option<&str>) {
+    let _ = match value {
+        Some(value) => element.set_attribute(name, value),
+        None => element.remove_attribute(name),
+    };
+}
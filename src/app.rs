@@ -0,0 +1,664 @@
+use color_eyre::eyre;
+use ratatui::{layout::{Position, Rect}, style::Style, widgets::Block, Terminal};
+use ratzilla::DomBackend;
+use std::io;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use wasm_bindgen::{JsCast, JsValue};
+
+use crate::Screen;
+use crate::accessibility::{Description, Mirror};
+use crate::crash::{CrashMsg, CrashScreen, CrashState, Report};
+use crate::error_page;
+use crate::grid::Grid;
+use crate::hit_test::HitMap;
+use crate::i18n::{self, Language};
+use crate::keys::{self, KeyPress};
+use crate::listeners::{self, Listeners, Scope};
+use crate::logging;
+use crate::overlay::{Overlay, Stats};
+use crate::preferences::Preferences;
+use crate::router;
+use crate::scheduler::Scheduler;
+use crate::state::{AppState, Effect, HistoryStep, Msg};
+use crate::startup::StartupScreen;
+use crate::home::HomeScreen;
+use crate::demo::demo::DemoScreen;
+use crate::blog::blog::{BlogScreen, PostScreen};
+use crate::blog::posts;
+use crate::contact::ContactScreen;
+use crate::theme::{self, ThemeName};
+use crate::zoom::Zoom;
+
+// Owns the terminal and the state store, and is the only place that draws
+pub struct App {
+    terminal: Terminal<DomBackend>,
+    state: AppState,
+    area: Rect,     // The whole terminal, screens draw in the zoomed part of it
+    grid: Option<Grid>,
+    hits: HitMap,
+    listeners: Listeners,
+    scheduler: Scheduler,
+    page_theme: Option<ThemeName>,  // Theme the page background was last set for
+    page_language: Option<Language>,    // Language the page's `lang` was last set for
+    page_zoom: Option<Zoom>,    // Zoom the page's font was last scaled for
+    mirror: Mirror,
+    overlay: Option<Overlay>,   // The debug overlay, while it is shown
+}
+
+// The one long-lived app instance, plus messages dispatched while it is busy
+thread_local! {
+    static APP: RefCell<Option<App>> = const { RefCell::new(None) };
+    static PENDING: RefCell<VecDeque<Msg>> = const { RefCell::new(VecDeque::new()) };
+}
+
+impl App {
+    pub fn init(state: AppState, overlay: bool) -> io::Result<()> {
+        let backend = DomBackend::new()?;
+        let terminal = Terminal::new(backend)?;
+        let start = state.screen();
+
+        let mut state = state;
+        state.size = zoomed_area(state.zoom, Rect::from((Position::ORIGIN, terminal.size()?))).as_size();
+
+        let mut app = App {
+            terminal,
+            state,
+            area: Rect::default(),
+            grid: None,
+            hits: HitMap::default(),
+            listeners: Listeners::default(),
+            scheduler: Scheduler::new(on_frame),
+            page_theme: None,
+            page_language: None,
+            page_zoom: None,
+            mirror: Mirror::new(),
+            overlay: overlay.then(Overlay::default),
+        };
+
+        app.setup_shortcuts()?;
+        app.setup_routing()?;
+        app.setup_resize()?;
+        app.setup_pointer()?;
+        app.setup_theme()?;
+        app.setup_mirror();
+
+        // Normalises the address, e.g. a link to a post that is gone
+        router::sync(HistoryStep::Replace(start));
+
+        app.enter(start)?;
+        app.draw()?;
+
+        APP.with(|cell| {
+            *cell.borrow_mut() = Some(app);
+        });
+
+        Ok(())
+    }
+
+    fn handle(&mut self, msg: Msg) -> io::Result<()> {
+        let from = self.state.screen();
+
+        let changed = self.state.update(msg);
+
+        for step in self.state.nav.take_steps() {
+            router::sync(step);
+        }
+
+        for effect in self.state.take_effects() {
+            if let Err(error) = perform(&effect, &self.state.preferences) {
+                log::warn!("Failed to carry out {:?}: {}", effect, error);
+            }
+        }
+
+        if !changed {
+            return Ok(());
+        }
+
+        let to = self.state.screen();
+        if to != from {
+            log::debug!("Navigating from {:?} to {:?}", from, to);
+
+            self.leave(from);
+            self.terminal.clear()?;
+            self.enter(to)?;
+        }
+
+        self.draw()
+    }
+
+    fn enter(&mut self, screen: Screen) -> io::Result<()> {
+        let listeners = &mut self.listeners;
+
+        let scheduler = &mut self.scheduler;
+
+        match screen {
+            Screen::Startup => {
+                StartupScreen::listen(listeners)?;
+                StartupScreen::subscribe(scheduler);
+            }
+            Screen::Home => HomeScreen::listen(listeners)?,
+            Screen::Demo => {
+                DemoScreen::listen(listeners)?;
+                DemoScreen::subscribe(scheduler);
+            }
+            Screen::Blog => BlogScreen::listen(listeners)?,
+            Screen::Post(index) => PostScreen::listen(listeners, index)?,
+            Screen::Contact => ContactScreen::listen(listeners)?,
+        }
+
+        Ok(())
+    }
+
+    fn leave(&mut self, screen: Screen) {
+        self.listeners.detach(Scope::Screen(screen));
+        self.scheduler.unsubscribe(Scope::Screen(screen));
+    }
+
+    // Swaps the current screen for the error screen, with its report. If
+    // even that can't be drawn, or the error screen itself fails, the page
+    // is replaced with a plain one.
+    fn fail(&mut self, error: eyre::Report) {
+        let report = Report::from_error(&error);
+        log::error!("{}", report.text());
+
+        if self.state.crash.is_some() {
+            error_page::show(&report);
+            return;
+        }
+
+        self.leave(self.state.screen());
+        self.state.crash = Some(CrashState::new(report.clone()));
+
+        let shown = CrashScreen::listen(&mut self.listeners)
+            .and_then(|()| self.terminal.clear())
+            .and_then(|()| self.draw());
+
+        if shown.is_err() {
+            error_page::show(&report);
+        }
+    }
+
+    fn draw(&mut self) -> io::Result<()> {
+        i18n::activate(self.state.language);
+
+        let started = self.overlay.as_mut().map(Overlay::start_draw);
+        let description = Self::describe(&self.state);
+
+        let state = &self.state;
+        let theme = state.theme.theme();
+        let zoom = state.zoom;
+        let hits = &mut self.hits;
+        hits.clear();
+
+        let overlay = self.overlay.as_ref();
+        let stats = overlay.map(|_| Stats {
+            screen: match state.crash {
+                Some(_) => "Crash".to_string(),
+                None => format!("{:?}", state.screen()),
+            },
+            focus: description.focused_label(),
+            listeners: self.listeners.counts(),
+            subscriptions: self.scheduler.subscriptions(),
+            zoom: zoom.percent(),
+        });
+
+        let completed = self.terminal.draw(|frame| {
+            // Paint every cell, so no terminal default shows through
+            let background = Block::default().style(Style::default().fg(theme.text).bg(theme.background));
+            frame.render_widget(background, frame.area());
+
+            let area = zoomed_area(zoom, frame.area());
+            match (&state.crash, state.screen()) {
+                (Some(crash), _) => CrashScreen::render(frame, area, crash, theme, hits),
+                (None, Screen::Startup) => StartupScreen::render(frame, area, &state.startup, theme),
+                (None, Screen::Home) => HomeScreen::render(frame, area, &state.home, theme, zoom, hits),
+                (None, Screen::Demo) => DemoScreen::render(frame, area, &state.demo, theme),
+                (None, Screen::Blog) => BlogScreen::render(frame, area, &state.blog, theme, hits),
+                (None, Screen::Post(index)) => PostScreen::render(frame, area, &state.post, theme, posts::all().get(index)),
+                (None, Screen::Contact) => ContactScreen::render(frame, area, &state.contact, theme, hits),
+            }
+
+            if let (Some(overlay), Some(stats)) = (overlay, &stats) {
+                overlay.render(frame, area, stats, theme, hits);
+            }
+        })?;
+
+        self.area = completed.area;
+        self.state.size = zoomed_area(zoom, completed.area).as_size();
+
+        // Re-measured on the next pointer event, once the DOM has settled
+        self.grid = None;
+
+        self.update_cursor();
+        self.update_page_theme();
+        self.update_page_language();
+        self.update_page_zoom();
+        self.mirror.sync(description);
+
+        if let (Some(overlay), Some(started)) = (self.overlay.as_mut(), started) {
+            overlay.end_draw(started);
+        }
+
+        Ok(())
+    }
+
+    fn describe(state: &AppState) -> Description {
+        if let Some(crash) = &state.crash {
+            return CrashScreen::describe(crash);
+        }
+
+        match state.screen() {
+            Screen::Startup => StartupScreen::describe(&state.startup),
+            Screen::Home => HomeScreen::describe(&state.home, state.theme, state.zoom),
+            Screen::Demo => DemoScreen::describe(&state.demo),
+            Screen::Blog => BlogScreen::describe(&state.blog),
+            Screen::Post(index) => PostScreen::describe(posts::all().get(index)),
+            Screen::Contact => ContactScreen::describe(&state.contact),
+        }
+    }
+
+    fn cell_at(&mut self, x: i32, y: i32) -> Option<Position> {
+        if self.grid.is_none() {
+            self.grid = Grid::measure(self.area);
+        }
+
+        self.grid?.cell_at(f64::from(x), f64::from(y))
+    }
+
+    // Track the pointer for widget hover/press states, redrawing only when
+    // that changes what is shown
+    fn point(&mut self, event: &web_sys::MouseEvent, pressed: bool) -> io::Result<()> {
+        let before = (self.hits.hovered_action().cloned(), self.hits.pressed);
+        let before_pointer = self.hits.pointer;
+
+        self.hits.pointer = self.cell_at(event.client_x(), event.client_y());
+        self.hits.pressed = pressed;
+
+        let hovered = self.hits.hovered_action().cloned();
+        let changed = if hovered.is_some() {
+            before != (hovered, pressed)
+        } else {
+            before.0.is_some()
+        };
+
+        // The overlay shows the cell under the pointer
+        let moved = self.overlay.is_some() && self.hits.pointer != before_pointer;
+
+        if changed || moved {
+            self.draw()?;
+        }
+
+        Ok(())
+    }
+
+    fn toggle_overlay(&mut self) -> io::Result<()> {
+        self.overlay = match self.overlay {
+            Some(_) => None,
+            None => Some(Overlay::default()),
+        };
+
+        log::debug!("Debug overlay {}", if self.overlay.is_some() { "on" } else { "off" });
+        self.draw()
+    }
+
+    fn update_cursor(&self) {
+        let body = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.body());
+
+        if let Some(body) = body {
+            let cursor = if self.hits.hovered_action().is_some() { "pointer" } else { "default" };
+            let _ = body.style().set_property("cursor", cursor);
+        }
+    }
+
+    // The page around the terminal follows the theme too
+    fn update_page_theme(&mut self) {
+        if self.page_theme == Some(self.state.theme) {
+            return;
+        }
+
+        let body = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.body());
+
+        if let Some(body) = body {
+            let theme = self.state.theme.theme();
+            let _ = body.style().set_property("background-color", theme.css_background);
+            let _ = body.style().set_property("color", theme.css_text);
+            self.page_theme = Some(self.state.theme);
+        }
+    }
+
+    // For screen readers, and the browser's own translation and hyphenation
+    fn update_page_language(&mut self) {
+        if self.page_language == Some(self.state.language) {
+            return;
+        }
+
+        let root = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.document_element());
+
+        if let Some(root) = root {
+            let _ = root.set_attribute("lang", self.state.language.code());
+            self.page_language = Some(self.state.language);
+        }
+    }
+
+    // Scales the font of the terminal rows. The grid is measured again on
+    // the next pointer event, so hit testing follows.
+    fn update_page_zoom(&mut self) {
+        if self.page_zoom == Some(self.state.zoom) {
+            return;
+        }
+
+        let body = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.body());
+
+        if let Some(body) = body {
+            let _ = body.style().set_property("--text-scale", &css_scale(self.state.zoom));
+            self.page_zoom = Some(self.state.zoom);
+        }
+    }
+
+    fn setup_pointer(&mut self) -> io::Result<()> {
+        let document = listeners::document()?;
+
+        self.listeners.add(Scope::App, &document, "mousemove", |event: web_sys::MouseEvent| {
+            with_app(|app| {
+                let pressed = app.hits.pressed;
+                app.point(&event, pressed)
+            });
+        });
+
+        self.listeners.add(Scope::App, &document, "mousedown", |event: web_sys::MouseEvent| {
+            with_app(|app| app.point(&event, true));
+        });
+
+        self.listeners.add(Scope::App, &document, "mouseup", |event: web_sys::MouseEvent| {
+            with_app(|app| app.point(&event, false));
+        });
+
+        // Clicks on registered widgets dispatch their action
+        self.listeners.add(Scope::App, &document, "click", |event: web_sys::MouseEvent| {
+            let action = APP.with(|cell| {
+                let mut app = cell.try_borrow_mut().ok()?;
+                let app = app.as_mut()?;
+                let cell = app.cell_at(event.client_x(), event.client_y())?;
+
+                log::debug!("Click at Col: {}, Row: {}", cell.x, cell.y);
+                app.hits.action_at(cell).cloned()
+            });
+
+            if let Some(action) = action {
+                dispatch(action);
+            }
+        });
+
+        Ok(())
+    }
+
+    // The browser's Back and Forward buttons, and edits to the URL. Both
+    // events fire for some of these, the second finds nothing to change.
+    fn setup_routing(&mut self) -> io::Result<()> {
+        let window = listeners::window()?;
+
+        for event_type in ["popstate", "hashchange"] {
+            self.listeners.add(Scope::App, &window, event_type, |_: web_sys::Event| {
+                dispatch(Msg::Route(router::current()));
+            });
+        }
+
+        Ok(())
+    }
+
+    // Window zoom fires resize too, and both change the grid geometry
+    fn setup_resize(&mut self) -> io::Result<()> {
+        let window = listeners::window()?;
+
+        self.listeners.add(Scope::App, &window, "resize", |_: web_sys::Event| {
+            dispatch(Msg::Resize);
+        });
+
+        Ok(())
+    }
+
+    // Screen readers activate the mirror's controls rather than the grid.
+    // Keys still reach the screens' document listeners as usual, except Tab,
+    // which moves through the mirror the browser's way.
+    fn setup_mirror(&mut self) {
+        let Some(root) = self.mirror.root() else {
+            return;
+        };
+
+        self.listeners.add(Scope::App, &root, "click", |event: web_sys::MouseEvent| {
+            // Not a click on the grid, whatever its coordinates say
+            event.stop_propagation();
+
+            let action = APP.with(|cell| {
+                let app = cell.try_borrow().ok()?;
+                app.as_ref()?.mirror.action_for(&event).cloned()
+            });
+
+            if let Some(action) = action {
+                dispatch(action);
+            }
+        });
+
+        // The screens take Tab for their own focus, which would keep
+        // everything on the page but the widgets they cycle through out of
+        // reach. Focus landing on a control takes the terminal's along.
+        self.listeners.add(Scope::App, &root, "keydown", |event: web_sys::KeyboardEvent| {
+            if event.key() == "Tab" && !event.ctrl_key() && !event.alt_key() && !event.meta_key() {
+                event.stop_propagation();
+            }
+        });
+
+        self.listeners.add(Scope::App, &root, "focusin", |event: web_sys::FocusEvent| {
+            // Nothing while the app itself moves focus, in `Mirror::sync`
+            let msg = APP.with(|cell| {
+                let app = cell.try_borrow().ok()?;
+                app.as_ref()?.mirror.focus_for(&event).cloned()
+            });
+
+            if let Some(msg) = msg {
+                dispatch(msg);
+            }
+        });
+    }
+
+    // Until the visitor picks a theme, it follows changes to the system's
+    // color preferences
+    fn setup_theme(&mut self) -> io::Result<()> {
+        let window = listeners::window()?;
+
+        for query in [theme::CONTRAST_QUERY, theme::LIGHT_QUERY] {
+            let Ok(Some(list)) = window.match_media(query) else {
+                continue;
+            };
+
+            self.listeners.add(Scope::App, &list, "change", |_: web_sys::Event| {
+                dispatch(Msg::SystemTheme(ThemeName::system()));
+            });
+        }
+
+        Ok(())
+    }
+
+    // Alt chords that work on every screen: Alt+Left / Alt+Right step
+    // through the app history like a browser, Alt+T and Alt+L cycle the
+    // theme and language, Alt+= / Alt+- / Alt+0 make the text larger,
+    // smaller or normal again. Alt+Shift+D switches debug logging, and
+    // Alt+Shift+O the debug overlay.
+    fn setup_shortcuts(&mut self) -> io::Result<()> {
+        let document = listeners::document()?;
+
+        // Capture phase so screen handlers never see the chord as a plain arrow
+        self.listeners.add_capture(Scope::App, &document, "keydown", |event: web_sys::KeyboardEvent| {
+            let press = KeyPress::from_event(&event);
+
+            // Developer tools, not app state
+            if press.alt && press.shift {
+                match press.code.as_str() {
+                    "KeyD" => logging::toggle(),
+                    "KeyO" => with_app(|app| app.toggle_overlay()),
+                    _ => return,
+                }
+
+                event.prevent_default();
+                event.stop_immediate_propagation();
+                return;
+            }
+
+            // Keys are text while a field has focus: Alt and an arrow moves
+            // by words on a Mac, and Alt and a letter types a symbol
+            if with_state(AppState::is_typing) == Some(true) {
+                return;
+            }
+
+            if let Some(msg) = keys::shortcut(&press) {
+                event.prevent_default();
+                event.stop_immediate_propagation();
+                dispatch(msg);
+            }
+        });
+
+        Ok(())
+    }
+}
+
+pub fn dispatch(msg: Msg) {
+    PENDING.with(|pending| pending.borrow_mut().push_back(msg));
+
+    APP.with(|cell| {
+        // Already handling a message further up the stack, which will drain
+        // the queue once it is done
+        let Ok(mut app) = cell.try_borrow_mut() else {
+            return;
+        };
+        let Some(app) = app.as_mut() else {
+            return;
+        };
+
+        while let Some(msg) = PENDING.with(|pending| pending.borrow_mut().pop_front()) {
+            let description = format!("{:?}", msg);
+            log::trace!("Handling {}", description);
+            if let Err(e) = app.handle(msg) {
+                app.fail(eyre::Report::new(e).wrap_err(format!("Failed to handle {}", description)));
+            }
+        }
+    });
+}
+
+// What an update asked the page to do. Failures cost the visitor that
+// one action and are only logged.
+fn perform(effect: &Effect, preferences: &Preferences) -> io::Result<()> {
+    let window = listeners::window()?;
+    let refused = |error: JsValue| io::Error::other(format!("{:?}", error));
+
+    match effect {
+        Effect::Open(url) => window.location().set_href(url).map_err(refused),
+        Effect::SavePreferences => {
+            preferences.save();
+            Ok(())
+        }
+        // A temporary link, which needs no Blob plumbing
+        Effect::Download { name, url } => {
+            let link = listeners::document()?.create_element("a").map_err(refused)?;
+            link.set_attribute("href", url).map_err(refused)?;
+            link.set_attribute("download", name).map_err(refused)?;
+            link.dyn_into::<web_sys::HtmlElement>().map_err(|_| io::Error::other("not an HTML element"))?.click();
+            Ok(())
+        }
+        Effect::Reload => window.location().reload().map_err(refused),
+        // The clipboard API only exists on secure pages, and the browser can
+        // still refuse the write. Either way Ctrl+C is left, see `CrashScreen::listen`.
+        Effect::Copy(text) => {
+            let written = window
+                .is_secure_context()
+                .then(|| wasm_bindgen_futures::JsFuture::from(window.navigator().clipboard().write_text(text)));
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let copied = match written {
+                    Some(written) => match written.await {
+                        Ok(_) => true,
+                        Err(error) => {
+                            log::warn!("Failed to copy the report: {:?}", error);
+                            false
+                        }
+                    },
+                    None => false,
+                };
+                dispatch(Msg::Crash(CrashMsg::Copied(copied)));
+            });
+            Ok(())
+        }
+    }
+}
+
+// The part of the terminal that fits in the window at this text size
+fn zoomed_area(zoom: Zoom, terminal: Rect) -> Rect {
+    let scale = |cells: u16| {
+        let scaled = u32::from(cells) * 100 / u32::from(zoom.percent());
+        u16::try_from(scaled).unwrap_or(cells).max(1)
+    };
+
+    Rect::new(terminal.x, terminal.y, scale(terminal.width), scale(terminal.height))
+}
+
+// Value for the page's `--text-scale`, which the font size is multiplied by
+fn css_scale(zoom: Zoom) -> String {
+    (f64::from(zoom.percent()) / 100.0).to_string()
+}
+
+// Read-only look at the state for DOM handlers that must answer right
+// away, like filling the clipboard. `None` while a message is handled.
+pub fn with_state<F, R>(f: F) -> Option<R>
+where
+    F: FnOnce(&AppState) -> R,
+{
+    APP.with(|cell| {
+        let app = cell.try_borrow().ok()?;
+        app.as_ref().map(|app| f(&app.state))
+    })
+}
+
+// Animation frame callback: hand out due ticks, then keep the loop going
+// for as long as something is subscribed
+fn on_frame(now: f64) {
+    let mut due = Vec::new();
+    with_app(|app| {
+        due = app.scheduler.due(now);
+        Ok(())
+    });
+
+    for msg in due {
+        dispatch(msg);
+    }
+
+    with_app(|app| {
+        app.scheduler.request_frame();
+        Ok(())
+    });
+}
+
+// Run glue code that needs the app outside of message handling
+fn with_app<F>(f: F)
+where
+    F: FnOnce(&mut App) -> io::Result<()>,
+{
+    APP.with(|cell| {
+        let Ok(mut app) = cell.try_borrow_mut() else {
+            return;
+        };
+
+        if let Some(app) = app.as_mut() {
+            if let Err(e) = f(app) {
+                app.fail(eyre::Report::new(e));
+            }
+        }
+    });
+}
@@ -0,0 +1,345 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Flex, Layout, Margin, Rect, Size},
+    style::{Style, Stylize},
+    widgets::{Block, Borders, Paragraph},
+    text::{Line, Span},
+    Frame,
+};
+#[cfg(not(feature = "native"))]
+use {
+    std::io,
+    crate::accessibility::{Control, Description},
+    crate::app,
+    crate::i18n::t_count,
+    crate::listeners::{self, Listeners, Scope},
+};
+
+use crate::Screen;
+use crate::i18n::t;
+use crate::buttons::{ArrowKeys, Button};
+use crate::hit_test::HitMap;
+use crate::keys::KeyPress;
+use crate::markdown;
+use crate::responsive::{self, Breakpoint};
+use crate::state::{Msg, Update};
+use crate::theme::Theme;
+use super::posts::{self, Post};
+
+// Rows taken by each entry in the post index, including spacing
+const ENTRY_HEIGHT: u16 = 6;
+
+// Widest the reader gets, long lines are hard to follow
+const READER_WIDTH: u16 = 80;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BlogState {
+    pub selected: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "native", allow(dead_code))]
+pub enum BlogMsg {
+    Previous,
+    Next,
+    Select(usize),  // From the screen reader mirror, which the terminal build doesn't have
+    Open,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PostState {
+    pub scroll: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PostMsg {
+    Scroll(i16),
+    Top,
+    Bottom,
+}
+
+// The post index
+pub struct BlogScreen;
+
+impl BlogScreen {
+    pub fn update(state: &mut BlogState, msg: BlogMsg) -> Update {
+        let count = posts::all().len();
+        if count == 0 {
+            return Update::Ignored;
+        }
+
+        match msg {
+            BlogMsg::Previous => state.selected = (state.selected + count - 1) % count,
+            BlogMsg::Next => state.selected = (state.selected + 1) % count,
+            BlogMsg::Select(index) => state.selected = index.min(count - 1),
+            BlogMsg::Open => return Update::Send(Msg::Navigate(Screen::Post(state.selected.min(count - 1)))),
+        }
+
+        Update::Redraw
+    }
+
+    pub fn render(frame: &mut Frame, area: Rect, state: &BlogState, theme: &Theme, hits: &mut HitMap) {
+
+        let layout = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Min(ENTRY_HEIGHT),
+            Constraint::Length(3),
+        ])
+        .split(area);
+
+        // Title
+        let title = Paragraph::new(t("blog.title"))
+            .style(Style::default().fg(theme.title).bold())
+            .alignment(Alignment::Center);
+        frame.render_widget(title, layout[0]);
+
+        let breakpoint = Breakpoint::of(area);
+        let list_area = layout[1].inner(Margin {
+            horizontal: breakpoint.margin().horizontal,
+            vertical: 0,
+        });
+
+        // Scroll the list just far enough to keep the selection in view
+        let visible = usize::from((list_area.height / ENTRY_HEIGHT).max(1));
+        let first = (state.selected + 1).saturating_sub(visible);
+
+        let tooltips: Vec<String> = posts::all()
+            .iter()
+            .map(|post| format!("{} · {}", post.date, post.summary))
+            .collect();
+
+        for (row, (index, post)) in posts::all().iter().enumerate().skip(first).take(visible).enumerate() {
+            let entry_area = Rect {
+                y: list_area.y + row as u16 * ENTRY_HEIGHT,
+                height: ENTRY_HEIGHT - 1,
+                ..list_area
+            };
+
+            let entry = Button::new(post.title, Msg::Navigate(Screen::Post(index)))
+                .tooltip(&tooltips[index])
+                .theme(theme)
+                .color(theme.highlight)
+                .focused(index == state.selected);
+
+            frame.render_stateful_widget(entry, entry_area, hits);
+        }
+
+        // Footer
+        let footer_text = match breakpoint {
+            Breakpoint::Compact => t("blog.footer.compact"),
+            Breakpoint::Regular | Breakpoint::Wide => t("blog.footer"),
+        };
+        let footer = Paragraph::new(footer_text)
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        frame.render_widget(footer, layout[2]);
+    }
+
+    pub fn key(press: &KeyPress) -> Option<Msg> {
+        // Leave browser and system shortcuts alone
+        if press.is_shortcut() {
+            return None;
+        }
+
+        let msg = match press.key.as_str() {
+            "Escape" => Msg::Back,
+            "Enter" | " " => Msg::Blog(BlogMsg::Open),
+            "Tab" if press.shift => Msg::Blog(BlogMsg::Previous),
+            "Tab" => Msg::Blog(BlogMsg::Next),
+            key => match ArrowKeys::from_key_string(key)? {
+                ArrowKeys::TopArrow => Msg::Blog(BlogMsg::Previous),
+                ArrowKeys::BottomArrow => Msg::Blog(BlogMsg::Next),
+                ArrowKeys::RightArrow => Msg::Blog(BlogMsg::Open),
+                ArrowKeys::LeftArrow => Msg::Back,
+            },
+        };
+
+        Some(msg)
+    }
+}
+
+// A single post
+pub struct PostScreen;
+
+impl PostScreen {
+    pub fn update(state: &mut PostState, post: Option<&Post>, size: Size, msg: PostMsg) -> Update {
+        let Some(post) = post else {
+            return Update::Ignored;
+        };
+
+        let max_scroll = Self::max_scroll(post, size);
+        let scroll = match msg {
+            PostMsg::Scroll(lines) => state.scroll.saturating_add_signed(lines).min(max_scroll),
+            PostMsg::Top => 0,
+            PostMsg::Bottom => max_scroll,
+        };
+
+        if scroll == state.scroll {
+            return Update::Ignored;
+        }
+
+        state.scroll = scroll;
+        Update::Redraw
+    }
+
+    pub fn render(frame: &mut Frame, area: Rect, state: &PostState, theme: &Theme, post: Option<&Post>) {
+        let [header_area, body_area, footer_area] = Self::layout(area);
+
+        let Some(post) = post else {
+            let missing = Paragraph::new(t("post.missing"))
+                .style(Style::default().fg(theme.muted))
+                .alignment(Alignment::Center);
+            frame.render_widget(missing, body_area);
+            return;
+        };
+
+        // Title and date
+        let header = Paragraph::new(vec![
+            Line::from(Span::styled(post.title, Style::default().fg(theme.title).bold())),
+            Line::from(Span::styled(post.date, Style::default().fg(theme.muted))),
+        ])
+        .alignment(Alignment::Center);
+        frame.render_widget(header, header_area);
+
+        let text = markdown::render(post.body, Self::text_area(body_area).width, theme);
+
+        let scroll = state.scroll.min(Self::max_scroll(post, area.as_size()));
+        let body = Paragraph::new(text)
+            .style(Style::default().fg(theme.text))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(theme.highlight))
+            )
+            .scroll((scroll, 0));
+        frame.render_widget(body, body_area);
+
+        // Footer
+        let footer_text = match Breakpoint::of(area) {
+            Breakpoint::Compact => t("post.footer.compact"),
+            Breakpoint::Regular | Breakpoint::Wide => t("post.footer"),
+        };
+        let footer = Paragraph::new(footer_text)
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        frame.render_widget(footer, footer_area);
+    }
+
+    fn layout(area: Rect) -> [Rect; 3] {
+        let short = responsive::is_short(area);
+        let layout = Layout::vertical([
+            Constraint::Length(if short { 2 } else { 3 }),  // Title and date
+            Constraint::Min(3),                             // Post
+            Constraint::Length(if short { 1 } else { 2 }),  // Footer
+        ])
+        .split(area);
+
+        let body_area = Layout::horizontal([Constraint::Max(READER_WIDTH)])
+            .flex(Flex::Center)
+            .split(layout[1])[0];
+
+        [layout[0], body_area, layout[2]]
+    }
+
+    // Inside the border, where the text goes
+    fn text_area(body_area: Rect) -> Rect {
+        body_area.inner(Margin {
+            horizontal: 1,
+            vertical: 1,
+        })
+    }
+
+    fn max_scroll(post: &Post, size: Size) -> u16 {
+        let [_, body_area, _] = Self::layout(Rect::from((ratatui::layout::Position::ORIGIN, size)));
+        let text_area = Self::text_area(body_area);
+        // Colors don't change how the text wraps, so any theme will do
+        let height = markdown::render(post.body, text_area.width, &Theme::default()).lines.len();
+
+        u16::try_from(height).unwrap_or(u16::MAX).saturating_sub(text_area.height)
+    }
+
+    pub fn key(press: &KeyPress) -> Option<Msg> {
+        // Leave browser and system shortcuts alone
+        if press.is_shortcut() {
+            return None;
+        }
+
+        let msg = match press.key.as_str() {
+            "Escape" | "ArrowLeft" | "Backspace" => Msg::Back,
+            "ArrowUp" => Msg::Post(PostMsg::Scroll(-1)),
+            "ArrowDown" => Msg::Post(PostMsg::Scroll(1)),
+            "PageUp" => Msg::Post(PostMsg::Scroll(-10)),
+            "PageDown" | " " => Msg::Post(PostMsg::Scroll(10)),
+            "Home" => Msg::Post(PostMsg::Top),
+            "End" => Msg::Post(PostMsg::Bottom),
+            _ => return None,
+        };
+
+        Some(msg)
+    }
+}
+
+#[cfg(not(feature = "native"))]
+impl BlogScreen {
+    pub fn describe(state: &BlogState) -> Description {
+        let description = Description::new(t("blog.title")).text(t_count("blog.count", posts::all().len() as u64, &[]));
+        let description = posts::all().iter().enumerate().fold(description, |description, (index, post)| {
+            description.control(
+                Control::button(post.title, Msg::Navigate(Screen::Post(index)))
+                    .hint(format!("{} · {}", post.date, post.summary))
+                    .on_focus(Msg::Blog(BlogMsg::Select(index)))
+                    .focused(index == state.selected),
+            )
+        });
+
+        description.control(Control::button(t("app.back"), Msg::Back))
+    }
+
+    pub fn listen(listeners: &mut Listeners) -> io::Result<()> {
+        let document = listeners::document()?;
+
+        listeners.add(Scope::Screen(Screen::Blog), &document, "keydown", |event: web_sys::KeyboardEvent| {
+            if let Some(msg) = Self::key(&KeyPress::from_event(&event)) {
+                event.prevent_default();
+                app::dispatch(msg);
+            }
+        });
+
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "native"))]
+impl PostScreen {
+    pub fn describe(post: Option<&Post>) -> Description {
+        let Some(post) = post else {
+            return Description::new(t("post.missing")).control(Control::button(t("app.back"), Msg::Back));
+        };
+
+        let description = Description::new(post.title).text(post.date);
+        markdown::describe(post.body, description).control(Control::button(t("app.back"), Msg::Back))
+    }
+
+    pub fn listen(listeners: &mut Listeners, post: usize) -> io::Result<()> {
+        let document = listeners::document()?;
+        let scope = Scope::Screen(Screen::Post(post));
+
+        listeners.add(scope, &document, "keydown", |event: web_sys::KeyboardEvent| {
+            if let Some(msg) = Self::key(&KeyPress::from_event(&event)) {
+                event.prevent_default();
+                app::dispatch(msg);
+            }
+        });
+
+        listeners.add(scope, &document, "wheel", |event: web_sys::WheelEvent| {
+            // Sideways scrolling, on a trackpad or a tilted wheel, has no
+            // vertical part and would otherwise count as scrolling down
+            if event.delta_y() == 0.0 {
+                return;
+            }
+            let lines = if event.delta_y() < 0.0 { -3 } else { 3 };
+            app::dispatch(Msg::Post(PostMsg::Scroll(lines)));
+        });
+
+        Ok(())
+    }
+}
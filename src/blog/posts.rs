@@ -0,0 +1,152 @@
+use std::sync::OnceLock;
+
+// `SOURCES`: (slug, file contents) for every file in `posts/`, generated by build.rs
+include!(concat!(env!("OUT_DIR"), "/posts.rs"));
+
+// A blog post. Posts are Markdown files with a small header:
+//
+//     ---
+//     title: How fall detection works
+//     date: 2026-10-02
+//     summary: One line for the post index.
+//     ---
+//
+// The slug is the file name without `.md`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Post {
+    pub slug: &'static str,
+    pub title: &'static str,
+    pub date: &'static str,
+    pub summary: &'static str,
+    pub body: &'static str,
+}
+
+impl Post {
+    pub fn parse(slug: &'static str, source: &'static str) -> Self {
+        let mut post = Post {
+            slug,
+            title: slug,
+            date: "",
+            summary: "",
+            body: source.trim(),
+        };
+
+        // Line by line, so files saved with Windows line endings (`\r\n`)
+        // have a header too
+        let mut lines = source.split_inclusive('\n');
+        let Some(first) = lines.next().filter(|line| line.trim_end() == "---") else {
+            return post;
+        };
+
+        let mut header = Vec::new();
+        let mut offset = first.len();
+        let mut closed = false;
+        for line in lines {
+            offset += line.len();
+            if line.trim_end() == "---" {
+                closed = true;
+                break;
+            }
+            header.push(line);
+        }
+        // Without the closing line it was a rule at the top, not a header
+        if !closed {
+            return post;
+        }
+
+        for line in header {
+            match line.split_once(':') {
+                Some(("title", value)) => post.title = value.trim(),
+                Some(("date", value)) => post.date = value.trim(),
+                Some(("summary", value)) => post.summary = value.trim(),
+                _ => {}
+            }
+        }
+
+        post.body = source[offset..].trim();
+        post
+    }
+}
+
+// Every post, newest first
+pub fn all() -> &'static [Post] {
+    static POSTS: OnceLock<Vec<Post>> = OnceLock::new();
+
+    POSTS.get_or_init(|| {
+        let mut posts: Vec<Post> = SOURCES
+            .iter()
+            .map(|(slug, source)| Post::parse(slug, source))
+            .collect();
+
+        // ISO dates sort correctly as strings
+        posts.sort_by(|a, b| b.date.cmp(a.date));
+        posts
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_fills_in_the_post() {
+        let post = Post::parse(
+            "hello",
+            "---\ntitle: Hello: again\ndate: 2026-10-02\nsummary:  Short. \n---\n\n# Hello\n\nBody.\n",
+        );
+
+        assert_eq!(post.slug, "hello");
+        assert_eq!(post.title, "Hello: again");
+        assert_eq!(post.date, "2026-10-02");
+        assert_eq!(post.summary, "Short.");
+        assert_eq!(post.body, "# Hello\n\nBody.");
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let post = Post::parse("hello", "---\r\ntitle: Hello\r\ndate: 2026-10-02\r\n---\r\nBody.\r\n");
+
+        assert_eq!(post.title, "Hello");
+        assert_eq!(post.date, "2026-10-02");
+        assert_eq!(post.body, "Body.");
+    }
+
+    #[test]
+    fn without_a_header() {
+        let post = Post::parse("hello", "\n# Hello\n\nBody.\n");
+
+        assert_eq!(post.title, "hello");
+        assert_eq!(post.date, "");
+        assert_eq!(post.summary, "");
+        assert_eq!(post.body, "# Hello\n\nBody.");
+    }
+
+    #[test]
+    fn missing_fields_keep_their_defaults() {
+        let post = Post::parse("hello", "---\ndate: 2026-10-02\nauthor: Anna\n---\nBody.");
+
+        assert_eq!(post.title, "hello");
+        assert_eq!(post.date, "2026-10-02");
+        assert_eq!(post.summary, "");
+        assert_eq!(post.body, "Body.");
+
+        let post = Post::parse("hello", "---\n---\nBody.");
+        assert_eq!(post.title, "hello");
+        assert_eq!(post.body, "Body.");
+    }
+
+    #[test]
+    fn unclosed_header_is_part_of_the_body() {
+        let source = "---\ntitle: Hello\n\nBody.";
+        let post = Post::parse("hello", source);
+
+        assert_eq!(post.title, "hello");
+        assert_eq!(post.body, source);
+    }
+
+    #[test]
+    fn posts_are_newest_first() {
+        let dates: Vec<_> = all().iter().map(|post| post.date).collect();
+        assert!(dates.windows(2).all(|pair| pair[0] >= pair[1]));
+    }
+}
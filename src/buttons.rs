@@ -1,4 +1,18 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph, StatefulWidget, Widget},
+};
+
+use crate::hit_test::HitMap;
+use crate::i18n::t;
+use crate::state::Msg;
+use crate::theme::Theme;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)]
 pub enum ArrowKeys {
     RightArrow,
     LeftArrow,
@@ -17,6 +31,16 @@ impl ArrowKeys {
         }
     }
 
+    // The inverse of `from_key_string`
+    pub fn as_key_string(&self) -> &'static str {
+        match self {
+            ArrowKeys::TopArrow => "ArrowUp",
+            ArrowKeys::BottomArrow => "ArrowDown",
+            ArrowKeys::LeftArrow => "ArrowLeft",
+            ArrowKeys::RightArrow => "ArrowRight",
+        }
+    }
+
     pub fn as_symbol(&self) -> &str {
         match self {
             ArrowKeys::TopArrow => "↑",
@@ -28,10 +52,179 @@ impl ArrowKeys {
 
     pub fn as_name(&self) -> &str {
         match self {
-            ArrowKeys::TopArrow => "Up",
-            ArrowKeys::BottomArrow => "Down",
-            ArrowKeys::LeftArrow => "Left",
-            ArrowKeys::RightArrow => "Right",
+            ArrowKeys::TopArrow => t("keys.up"),
+            ArrowKeys::BottomArrow => t("keys.down"),
+            ArrowKeys::LeftArrow => t("keys.left"),
+            ArrowKeys::RightArrow => t("keys.right"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ButtonState {
+    Normal,
+    Hovered,
+    Focused,
+    Pressed,
+    Disabled,
+}
+
+// A bordered, clickable button. Rendered as a `StatefulWidget` with the
+// app's `HitMap` it registers its area, so clicking it dispatches `action`
+// and pointer hover/press are picked up without any screen code.
+pub struct Button<'a> {
+    title: &'a str,
+    tooltip: &'a str,
+    color: Color,
+    action: Msg,
+    accelerator: Option<char>,
+    focused: bool,
+    disabled: bool,
+    theme: Theme,
+}
+
+impl<'a> Button<'a> {
+    pub fn new(title: &'a str, action: Msg) -> Self {
+        Self {
+            title,
+            tooltip: "",
+            color: Color::White,
+            action,
+            accelerator: None,
+            focused: false,
+            disabled: false,
+            theme: Theme::default(),
+        }
+    }
+
+    pub fn tooltip(mut self, tooltip: &'a str) -> Self {
+        self.tooltip = tooltip;
+        self
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    // Shortcut key, marked in the title as e.g. "[D]emo"
+    pub fn accelerator(mut self, key: char) -> Self {
+        self.accelerator = Some(key);
+        self
+    }
+
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    // Text, focus and disabled colors; `color` stays the button's own
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.theme = *theme;
+        self
+    }
+
+    fn state(&self, hovered: bool, pressed: bool) -> ButtonState {
+        if self.disabled {
+            ButtonState::Disabled
+        } else if hovered && pressed {
+            ButtonState::Pressed
+        } else if hovered {
+            ButtonState::Hovered
+        } else if self.focused {
+            ButtonState::Focused
+        } else {
+            ButtonState::Normal
         }
     }
+
+    fn label(&self) -> String {
+        let marked = self.accelerator.and_then(|key| {
+            self.title
+                .char_indices()
+                .find(|(_, c)| c.eq_ignore_ascii_case(&key))
+        });
+
+        match marked {
+            Some((i, c)) => format!("{}[{}]{}", &self.title[..i], c, &self.title[i + c.len_utf8()..]),
+            None => self.title.to_string(),
+        }
+    }
+
+    fn draw(self, area: Rect, buf: &mut Buffer, state: ButtonState) {
+        let color = self.color;
+        let theme = self.theme;
+
+        let (bg_color, border_color, text_color, tooltip_color) = match state {
+            ButtonState::Normal => (theme.background, color, theme.text, theme.muted),
+            ButtonState::Hovered => (color, theme.focus, theme.background, theme.background),
+            ButtonState::Focused => (theme.background, theme.focus, color, theme.muted),
+            ButtonState::Pressed => (theme.text, color, theme.background, theme.background),
+            ButtonState::Disabled => (theme.background, theme.faint, theme.faint, theme.faint),
+        };
+
+        let border_type = if state == ButtonState::Focused {
+            BorderType::Double
+        } else {
+            BorderType::Plain
+        };
+
+        let content = vec![
+            Line::from(vec![
+                Span::styled(
+                    format!("  {}  ", self.label()),
+                    Style::default()
+                        .fg(text_color)
+                        .bold()
+                ),
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled(
+                    self.tooltip,
+                    Style::default()
+                        .fg(tooltip_color)
+                        .italic()
+                ),
+            ]),
+        ];
+
+        Paragraph::new(content)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(border_type)
+                    .border_style(Style::default().fg(border_color))
+                    .style(Style::default().bg(bg_color))
+            )
+            .alignment(Alignment::Center)
+            .render(area, buf);
+    }
+}
+
+impl Widget for Button<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let state = self.state(false, false);
+        self.draw(area, buf, state);
+    }
+}
+
+impl StatefulWidget for Button<'_> {
+    type State = HitMap;
+
+    fn render(self, area: Rect, buf: &mut Buffer, hits: &mut HitMap) {
+        let state = self.state(hits.is_hovered(area), hits.pressed);
+
+        // Disabled buttons are not clickable at all
+        if !self.disabled {
+            hits.register(area, self.action.clone());
+        }
+
+        self.draw(area, buf, state);
+    }
 }
@@ -0,0 +1,615 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Flex, Layout, Rect},
+    style::{Style, Stylize},
+    text::Line,
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+#[cfg(not(feature = "native"))]
+use {
+    std::io,
+    crate::Screen,
+    crate::accessibility::{Control, Description},
+    crate::app,
+    crate::listeners::{self, Listeners, Scope},
+};
+
+use crate::i18n::{t, t_with};
+use crate::buttons::Button;
+use crate::hit_test::HitMap;
+use crate::keys::KeyPress;
+use crate::responsive::{self, Breakpoint};
+use crate::state::{Effect, Msg, Update};
+use crate::text_input::{Edit, Motion, TextField, TextInput};
+use crate::theme::Theme;
+
+// Where messages go. Nothing is sent from the site itself: submitting
+// opens the visitor's mail app, or saves the message as a file.
+pub const CONTACT_EMAIL: &str = "lorem@ipsummail.com";
+
+const DOWNLOAD_NAME: &str = "alertangel-message.txt";
+
+// Widest the form gets
+const FORM_WIDTH: u16 = 72;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Name,
+    Email,
+    Subject,
+    Message,
+}
+
+impl Field {
+    pub const ALL: [Field; 4] = [Field::Name, Field::Email, Field::Subject, Field::Message];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Field::Name => t("contact.name"),
+            Field::Email => t("contact.email"),
+            Field::Subject => t("contact.subject"),
+            Field::Message => t("contact.message"),
+        }
+    }
+
+    fn placeholder(self) -> &'static str {
+        match self {
+            Field::Name => t("contact.name.placeholder"),
+            Field::Email => t("contact.email.placeholder"),
+            Field::Subject => t("contact.subject.placeholder"),
+            Field::Message => t("contact.message.placeholder"),
+        }
+    }
+
+    // An empty input with the field's length limit
+    fn input(self) -> TextInput {
+        let max_len = match self {
+            Field::Name => 80,
+            Field::Email => 254,
+            Field::Subject => 120,
+            Field::Message => 4000,
+        };
+
+        TextInput::new()
+            .max_len(max_len)
+            .multiline(self == Field::Message)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delivery {
+    Email,      // Prefilled `mailto:` link
+    Download,   // Plain text file
+}
+
+impl Delivery {
+    pub const ALL: [Delivery; 2] = [Delivery::Email, Delivery::Download];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Delivery::Email => t("contact.send"),
+            Delivery::Download => t("contact.download"),
+        }
+    }
+
+    pub fn tooltip(self) -> String {
+        match self {
+            Delivery::Email => t_with("contact.send.tooltip", &[("email", &CONTACT_EMAIL)]),
+            Delivery::Download => t("contact.download.tooltip").to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Focus {
+    Field(Field),
+    Submit(Delivery),
+}
+
+impl Focus {
+    // Tab order
+    pub const ORDER: [Focus; 6] = [
+        Focus::Field(Field::Name),
+        Focus::Field(Field::Email),
+        Focus::Field(Field::Subject),
+        Focus::Field(Field::Message),
+        Focus::Submit(Delivery::Email),
+        Focus::Submit(Delivery::Download),
+    ];
+}
+
+impl Default for Focus {
+    fn default() -> Self {
+        Focus::Field(Field::Name)
+    }
+}
+
+// Outcome of the last submit, shown under the form
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Invalid,
+    Sent(Delivery),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContactState {
+    pub name: TextInput,
+    pub email: TextInput,
+    pub subject: TextInput,
+    pub message: TextInput,
+    pub focus: Focus,
+    // Errors are only shown once the visitor tried to submit
+    pub show_errors: bool,
+    pub status: Option<Status>,
+}
+
+impl Default for ContactState {
+    fn default() -> Self {
+        Self {
+            name: Field::Name.input(),
+            email: Field::Email.input(),
+            subject: Field::Subject.input(),
+            message: Field::Message.input(),
+            focus: Focus::default(),
+            show_errors: false,
+            status: None,
+        }
+    }
+}
+
+impl ContactState {
+    pub fn input(&self, field: Field) -> &TextInput {
+        match field {
+            Field::Name => &self.name,
+            Field::Email => &self.email,
+            Field::Subject => &self.subject,
+            Field::Message => &self.message,
+        }
+    }
+
+    fn input_mut(&mut self, field: Field) -> &mut TextInput {
+        match field {
+            Field::Name => &mut self.name,
+            Field::Email => &mut self.email,
+            Field::Subject => &mut self.subject,
+            Field::Message => &mut self.message,
+        }
+    }
+
+    pub fn value(&self, field: Field) -> &str {
+        self.input(field).value()
+    }
+
+    pub fn error(&self, field: Field) -> Option<&'static str> {
+        let value = self.value(field).trim();
+
+        match field {
+            Field::Name if value.is_empty() => Some(t("contact.name.missing")),
+            Field::Email if value.is_empty() => Some(t("contact.email.missing")),
+            Field::Email if !is_email(value) => Some(t("contact.email.invalid")),
+            Field::Subject if value.is_empty() => Some(t("contact.subject.missing")),
+            Field::Message if value.is_empty() => Some(t("contact.message.missing")),
+            _ => None,
+        }
+    }
+
+    fn letter(&self) -> String {
+        format!(
+            "To: {}\nFrom: {} <{}>\nSubject: {}\n\n{}\n",
+            CONTACT_EMAIL,
+            self.value(Field::Name).trim(),
+            self.value(Field::Email).trim(),
+            self.value(Field::Subject).trim(),
+            self.value(Field::Message).trim(),
+        )
+    }
+
+    fn mailto(&self) -> String {
+        let body = format!("{}\n\n{}", self.value(Field::Message).trim(), self.value(Field::Name).trim());
+
+        format!(
+            "mailto:{}?subject={}&body={}",
+            CONTACT_EMAIL,
+            percent_encode(self.value(Field::Subject).trim()),
+            percent_encode(&body),
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContactMsg {
+    FocusNext,
+    FocusPrevious,
+    Focus(Focus),
+    Edit(Edit),
+    Submit(Delivery),
+}
+
+pub struct ContactScreen;
+
+impl ContactScreen {
+    pub fn update(state: &mut ContactState, msg: ContactMsg) -> Update {
+        match msg {
+            ContactMsg::FocusNext => return Self::move_focus(state, true),
+            ContactMsg::FocusPrevious => return Self::move_focus(state, false),
+            ContactMsg::Focus(focus) => {
+                if state.focus == focus {
+                    return Update::Ignored;
+                }
+                state.focus = focus;
+            }
+            ContactMsg::Edit(edit) => return Self::edit(state, edit),
+            ContactMsg::Submit(delivery) => {
+                state.show_errors = true;
+
+                if let Some(field) = Field::ALL.into_iter().find(|field| state.error(*field).is_some()) {
+                    state.focus = Focus::Field(field);
+                    state.status = Some(Status::Invalid);
+                    return Update::Redraw;
+                }
+
+                state.status = Some(Status::Sent(delivery));
+                return Update::Perform(Self::deliver(state, delivery));
+            }
+        }
+
+        Update::Redraw
+    }
+
+    // Keys a field has no use for move between fields or press buttons
+    fn edit(state: &mut ContactState, edit: Edit) -> Update {
+        let multiline = match state.focus {
+            Focus::Field(field) => state.input(field).is_multiline(),
+            Focus::Submit(_) => false,
+        };
+
+        let next = match (&edit, state.focus) {
+            (Edit::Insert(text), Focus::Submit(delivery)) if text == " " || text == "\n" => ContactMsg::Submit(delivery),
+            (Edit::Insert(text), _) if text == "\n" && !multiline => ContactMsg::FocusNext,
+            (Edit::Move(Motion::Up, _), _) if !multiline => ContactMsg::FocusPrevious,
+            (Edit::Move(Motion::Down, _), _) if !multiline => ContactMsg::FocusNext,
+            (_, Focus::Field(field)) => {
+                if !state.input_mut(field).apply(edit) {
+                    return Update::Ignored;
+                }
+                state.status = None;
+                return Update::Redraw;
+            }
+            (_, Focus::Submit(_)) => return Update::Ignored,
+        };
+
+        Update::Send(Msg::Contact(next))
+    }
+
+    fn move_focus(state: &mut ContactState, forward: bool) -> Update {
+        let count = Focus::ORDER.len();
+        let current = Focus::ORDER.iter().position(|focus| *focus == state.focus).unwrap_or(0);
+        let next = if forward { (current + 1) % count } else { (current + count - 1) % count };
+
+        state.focus = Focus::ORDER[next];
+        Update::Redraw
+    }
+
+    // The browser hands mailto links to the mail app without leaving the
+    // page, and a data link with a file name saves the message as a file
+    fn deliver(state: &ContactState, delivery: Delivery) -> Effect {
+        match delivery {
+            Delivery::Email => Effect::Open(state.mailto()),
+            Delivery::Download => Effect::Download {
+                name: DOWNLOAD_NAME,
+                url: format!("data:text/plain;charset=utf-8,{}", percent_encode(&state.letter())),
+            },
+        }
+    }
+
+    pub fn render(frame: &mut Frame, area: Rect, state: &ContactState, theme: &Theme, hits: &mut HitMap) {
+        let short = responsive::is_short(area);
+
+        let layout = Layout::vertical([
+            Constraint::Length(if short { 1 } else { 3 }),  // Title
+            Constraint::Min(0),                             // Form
+            Constraint::Length(if short { 1 } else { 2 }),  // Footer
+        ])
+        .split(area);
+
+        // Title
+        let title = Paragraph::new(t("contact.title"))
+            .style(Style::default().fg(theme.title).bold())
+            .alignment(Alignment::Center);
+        frame.render_widget(title, layout[0]);
+
+        let form_area = Layout::horizontal([Constraint::Max(FORM_WIDTH)])
+            .flex(Flex::Center)
+            .split(layout[1])[0];
+
+        let form = Layout::vertical([
+            Constraint::Length(3),      // Name
+            Constraint::Length(3),      // Email
+            Constraint::Length(3),      // Subject
+            Constraint::Min(3),                             // Message
+            Constraint::Length(1),                          // Status
+            Constraint::Length(if short { 3 } else { 5 }),  // Buttons
+        ])
+        .split(form_area);
+
+        for (field, field_area) in Field::ALL.into_iter().zip(form.iter()) {
+            Self::field(frame, state, theme, field, *field_area, hits);
+        }
+
+        // Status
+        let status_color = match state.status {
+            Some(Status::Invalid) => theme.alert,
+            Some(Status::Sent(_)) => theme.success,
+            None => theme.muted,
+        };
+        let status = Line::from(Self::status_text(state)).fg(status_color);
+        frame.render_widget(Paragraph::new(status).alignment(Alignment::Center), form[4]);
+
+        // Buttons
+        let buttons = Layout::horizontal([Constraint::Fill(1), Constraint::Length(2), Constraint::Fill(1)])
+            .split(form[5]);
+
+        let placement = [(buttons[0], theme.success), (buttons[2], theme.accent)];
+        for (delivery, (button_area, color)) in Delivery::ALL.into_iter().zip(placement) {
+            let tooltip = delivery.tooltip();
+            let button = Button::new(delivery.label(), Msg::Contact(ContactMsg::Submit(delivery)))
+                .tooltip(&tooltip)
+                .theme(theme)
+                .color(color)
+                .focused(state.focus == Focus::Submit(delivery));
+            frame.render_stateful_widget(button, button_area, hits);
+        }
+
+        // Footer
+        let footer_text = match Breakpoint::of(area) {
+            Breakpoint::Compact => t("contact.footer.compact"),
+            Breakpoint::Regular | Breakpoint::Wide => t("contact.footer"),
+        };
+        let footer = Paragraph::new(footer_text)
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        frame.render_widget(footer, layout[2]);
+    }
+
+    fn field(frame: &mut Frame, state: &ContactState, theme: &Theme, field: Field, area: Rect, hits: &mut HitMap) {
+        let focused = state.focus == Focus::Field(field);
+        let error = state.error(field).filter(|_| state.show_errors);
+
+        let border_color = match (focused, error) {
+            (_, Some(_)) => theme.alert,
+            (true, None) => theme.focus,
+            (false, None) => theme.border,
+        };
+
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(if focused { BorderType::Double } else { BorderType::Plain })
+            .border_style(Style::default().fg(border_color))
+            .title(format!(" {} ", field.label()));
+        if let Some(error) = error {
+            block = block.title_bottom(Line::from(format!(" {} ", error)).fg(theme.alert));
+        }
+
+        let text_field = TextField::new(state.input(field))
+            .block(block)
+            .placeholder(field.placeholder())
+            .focused(focused)
+            .theme(theme);
+
+        frame.render_widget(text_field, area);
+        hits.register(area, Msg::Contact(ContactMsg::Focus(Focus::Field(field))));
+    }
+
+    fn status_text(state: &ContactState) -> String {
+        match state.status {
+            Some(Status::Invalid) => t("contact.invalid").to_string(),
+            Some(Status::Sent(Delivery::Email)) => t("contact.sent").to_string(),
+            Some(Status::Sent(Delivery::Download)) => t_with("contact.saved", &[("file", &DOWNLOAD_NAME)]),
+            None => t_with("contact.direct", &[("email", &CONTACT_EMAIL)]),
+        }
+    }
+
+    pub fn key(press: &KeyPress) -> Option<Msg> {
+        let msg = match press.key.as_str() {
+            "Escape" => Msg::Back,
+            "Tab" if press.shift => Msg::Contact(ContactMsg::FocusPrevious),
+            "Tab" => Msg::Contact(ContactMsg::FocusNext),
+            _ => Msg::Contact(ContactMsg::Edit(Edit::from_key(press)?)),
+        };
+
+        Some(msg)
+    }
+}
+
+// Loose check, the mail app has the final say
+fn is_email(value: &str) -> bool {
+    let Some((local, domain)) = value.split_once('@') else {
+        return false;
+    };
+
+    !local.is_empty()
+        && !value.contains(char::is_whitespace)
+        && !domain.contains('@')
+        && domain.split('.').count() > 1
+        && domain.split('.').all(|part| !part.is_empty())
+}
+
+// Everything but RFC 3986 unreserved characters, for URLs
+fn percent_encode(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+
+    for byte in text.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => encoded.push(char::from(byte)),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+
+    encoded
+}
+
+#[cfg(not(feature = "native"))]
+impl ContactScreen {
+    pub fn describe(state: &ContactState) -> Description {
+        let description = Field::ALL.into_iter().fold(Description::new(t("contact.title")), |description, field| {
+            description.control(
+                Control::text_box(field.label(), state.value(field), Msg::Contact(ContactMsg::Focus(Focus::Field(field))))
+                    .multiline(state.input(field).is_multiline())
+                    .hint(field.placeholder())
+                    .error(state.error(field).filter(|_| state.show_errors))
+                    .on_focus(Msg::Contact(ContactMsg::Focus(Focus::Field(field))))
+                    .focused(state.focus == Focus::Field(field)),
+            )
+        });
+
+        let description = Delivery::ALL.into_iter().fold(description, |description, delivery| {
+            description.control(
+                Control::button(delivery.label(), Msg::Contact(ContactMsg::Submit(delivery)))
+                    .hint(delivery.tooltip())
+                    .on_focus(Msg::Contact(ContactMsg::Focus(Focus::Submit(delivery))))
+                    .focused(state.focus == Focus::Submit(delivery)),
+            )
+        });
+
+        // The status line only needs announcing once something happened
+        let description = description.control(Control::button(t("app.back"), Msg::Back));
+        match state.status {
+            Some(_) => description.status(Self::status_text(state)),
+            None => description.text(Self::status_text(state)),
+        }
+    }
+
+    pub fn listen(listeners: &mut Listeners) -> io::Result<()> {
+        let document = listeners::document()?;
+
+        let scope = Scope::Screen(Screen::Contact);
+
+        listeners.add(scope, &document, "keydown", |event: web_sys::KeyboardEvent| {
+            if let Some(msg) = Self::key(&KeyPress::from_event(&event)) {
+                event.prevent_default();
+                app::dispatch(msg);
+            }
+        });
+
+        listeners.add(scope, &document, "paste", |event: web_sys::ClipboardEvent| {
+            let text = event
+                .clipboard_data()
+                .and_then(|data| data.get_data("text/plain").ok())
+                .unwrap_or_default();
+
+            if !text.is_empty() {
+                event.prevent_default();
+                app::dispatch(Msg::Contact(ContactMsg::Edit(Edit::Insert(text))));
+            }
+        });
+
+        listeners.add(scope, &document, "copy", |event: web_sys::ClipboardEvent| {
+            Self::copy(&event, false);
+        });
+
+        listeners.add(scope, &document, "cut", |event: web_sys::ClipboardEvent| {
+            Self::copy(&event, true);
+        });
+
+        Ok(())
+    }
+
+    // Nothing on the page is really selected, so the clipboard is filled
+    // from the focused field's selection instead
+    fn copy(event: &web_sys::ClipboardEvent, cut: bool) {
+        let selected = app::with_state(|state| match state.contact.focus {
+            Focus::Field(field) => {
+                let input = state.contact.input(field);
+                input.selection().map(|range| input.value().chars().skip(range.start).take(range.len()).collect::<String>())
+            }
+            Focus::Submit(_) => None,
+        });
+
+        let (Some(Some(text)), Some(data)) = (selected, event.clipboard_data()) else {
+            return;
+        };
+
+        if data.set_data("text/plain", &text).is_ok() {
+            event.prevent_default();
+            if cut {
+                app::dispatch(Msg::Contact(ContactMsg::Edit(Edit::DeleteBackward)));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filled(values: [&str; 4]) -> ContactState {
+        let mut state = ContactState::default();
+        for (field, value) in Field::ALL.into_iter().zip(values) {
+            state.input_mut(field).apply(Edit::Insert(value.to_string()));
+        }
+        state
+    }
+
+    #[test]
+    fn emails_are_checked_loosely() {
+        for valid in ["a@b.co", "first.last+tag@mail.example.org"] {
+            assert!(is_email(valid), "{}", valid);
+        }
+        for invalid in ["", "a", "a@", "@b.co", "a@b", "a@b.", "a@.b", "a@@b.co", "a@b@c.co", "a b@c.co"] {
+            assert!(!is_email(invalid), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn each_field_has_its_own_error() {
+        let empty = ContactState::default();
+        assert_eq!(empty.error(Field::Name), Some(t("contact.name.missing")));
+        assert_eq!(empty.error(Field::Email), Some(t("contact.email.missing")));
+        assert_eq!(empty.error(Field::Subject), Some(t("contact.subject.missing")));
+        assert_eq!(empty.error(Field::Message), Some(t("contact.message.missing")));
+
+        let state = filled(["  ", "not an address", "Hello", "Text"]);
+        assert_eq!(state.error(Field::Name), Some(t("contact.name.missing")));
+        assert_eq!(state.error(Field::Email), Some(t("contact.email.invalid")));
+        assert_eq!(state.error(Field::Subject), None);
+        assert_eq!(state.error(Field::Message), None);
+    }
+
+    #[test]
+    fn invalid_submit_focuses_the_first_error() {
+        let mut state = filled(["Ann", "ann@example.com", "", ""]);
+
+        let update = ContactScreen::update(&mut state, ContactMsg::Submit(Delivery::Email));
+        assert_eq!(update, Update::Redraw);
+        assert_eq!(state.focus, Focus::Field(Field::Subject));
+        assert_eq!(state.status, Some(Status::Invalid));
+        assert!(state.show_errors);
+    }
+
+    #[test]
+    fn submit_asks_the_page_to_deliver() {
+        let mut state = filled(["Ann", "ann@example.com", "Hi & bye?", "Grüße\n100%"]);
+
+        let update = ContactScreen::update(&mut state, ContactMsg::Submit(Delivery::Email));
+        let mailto = format!(
+            "mailto:{}?subject=Hi%20%26%20bye%3F&body=Gr%C3%BC%C3%9Fe%0A100%25%0A%0AAnn",
+            CONTACT_EMAIL,
+        );
+        assert_eq!(update, Update::Perform(Effect::Open(mailto)));
+        assert_eq!(state.status, Some(Status::Sent(Delivery::Email)));
+
+        let Update::Perform(Effect::Download { name, url }) = ContactScreen::update(&mut state, ContactMsg::Submit(Delivery::Download)) else {
+            panic!("expected a download");
+        };
+        assert_eq!(name, DOWNLOAD_NAME);
+        assert!(url.starts_with("data:text/plain;charset=utf-8,To%3A%20"));
+        assert!(url.ends_with("Gr%C3%BC%C3%9Fe%0A100%25%0A"));
+    }
+
+    #[test]
+    fn percent_encoding_keeps_unreserved_characters() {
+        assert_eq!(percent_encode("AZaz09-_.~"), "AZaz09-_.~");
+        assert_eq!(percent_encode("a b/c?d=e&f"), "a%20b%2Fc%3Fd%3De%26f");
+        assert_eq!(percent_encode("日"), "%E6%97%A5");
+    }
+}
@@ -0,0 +1,241 @@
+use color_eyre::eyre;
+use ratatui::{
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Style, Stylize},
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    Frame,
+};
+#[cfg(not(feature = "native"))]
+use {
+    std::io,
+    crate::accessibility::{Control, Description},
+    crate::app,
+    crate::listeners::{self, Listeners, Scope},
+};
+
+use crate::buttons::Button;
+use crate::hit_test::HitMap;
+use crate::i18n::t;
+use crate::keys::KeyPress;
+use crate::responsive::Breakpoint;
+use crate::state::{Effect, Msg, Update};
+use crate::theme::Theme;
+
+// What went wrong, shown on the error screen and copied into bug reports:
+// color-eyre's report, with the message, its causes and where it happened
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub details: String,
+}
+
+impl Report {
+    // The terminal build has no error screen to send errors to, it stops
+    #[cfg_attr(feature = "native", allow(dead_code))]
+    pub fn from_error(error: &eyre::Report) -> Self {
+        Self {
+            details: format!("{:?}", error),
+        }
+    }
+
+    // Everything a bug report needs, as plain text
+    pub fn text(&self) -> String {
+        let window = web_sys::window();
+        let page = window.as_ref().and_then(|window| window.location().href().ok()).unwrap_or_default();
+        let browser = window.as_ref().and_then(|window| window.navigator().user_agent().ok()).unwrap_or_default();
+
+        format!(
+            "AlertAngel {}\nPage: {}\nBrowser: {}\n\n{}",
+            env!("CARGO_PKG_VERSION"),
+            page,
+            browser,
+            self.details,
+        )
+    }
+}
+
+pub struct CrashScreen;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrashButton {
+    Restart,
+    CopyReport,
+}
+
+impl CrashButton {
+    pub const ALL: [CrashButton; 2] = [CrashButton::Restart, CrashButton::CopyReport];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            CrashButton::Restart => t("crash.restart"),
+            CrashButton::CopyReport => t("crash.copy"),
+        }
+    }
+
+    // The first letter of the label, like the home screen's buttons
+    pub fn accelerator(self) -> char {
+        self.label().chars().next().map_or(' ', |c| c.to_ascii_lowercase())
+    }
+
+    fn msg(self) -> Msg {
+        match self {
+            CrashButton::Restart => Msg::Crash(CrashMsg::Restart),
+            CrashButton::CopyReport => Msg::Crash(CrashMsg::CopyReport),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrashState {
+    pub report: Report,
+    pub copied: Option<bool>,   // Whether the last copy went to the clipboard
+}
+
+impl CrashState {
+    #[cfg_attr(feature = "native", allow(dead_code))]
+    pub fn new(report: Report) -> Self {
+        Self { report, copied: None }
+    }
+}
+
+// `Copied` is the page's answer, which the terminal build never gives
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "native", allow(dead_code))]
+pub enum CrashMsg {
+    Restart,
+    CopyReport,
+    Copied(bool),   // Whether the report reached the clipboard, from either way of copying
+}
+
+impl CrashScreen {
+    pub fn update(state: &mut CrashState, msg: CrashMsg) -> Update {
+        match msg {
+            CrashMsg::Restart => Update::Perform(Effect::Reload),
+            // The status waits for the clipboard's answer, which can be a
+            // refusal
+            CrashMsg::CopyReport => Update::Perform(Effect::Copy(state.report.text())),
+            CrashMsg::Copied(copied) => {
+                state.copied = Some(copied);
+                Update::Redraw
+            }
+        }
+    }
+
+    pub fn render(frame: &mut Frame, area: Rect, state: &CrashState, theme: &Theme, hits: &mut HitMap) {
+        let area = area.inner(Breakpoint::of(area).margin());
+
+        let layout = Layout::vertical([
+            Constraint::Length(2),  // Title
+            Constraint::Length(3),  // Message
+            Constraint::Min(3),     // Report
+            Constraint::Length(3),  // Buttons
+            Constraint::Length(2),  // Status
+        ])
+            .split(area);
+
+        let title = Paragraph::new(t("crash.title"))
+            .style(Style::default().fg(theme.alert).bold())
+            .alignment(Alignment::Center);
+        frame.render_widget(title, layout[0]);
+
+        let message = Paragraph::new(t("crash.message"))
+            .style(Style::default().fg(theme.text))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+        frame.render_widget(message, layout[1]);
+
+        let report = Paragraph::new(state.report.text())
+            .style(Style::default().fg(theme.muted))
+            .wrap(Wrap { trim: false })
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(theme.border))
+                    .title(format!(" {} ", t("crash.report")))
+            );
+        frame.render_widget(report, layout[2]);
+
+        let buttons = Layout::horizontal([Constraint::Fill(1); 2])
+            .spacing(2)
+            .split(layout[3]);
+
+        for (button, button_area) in CrashButton::ALL.into_iter().zip(buttons.iter()) {
+            let widget = Button::new(button.label(), button.msg())
+                .theme(theme)
+                .color(if button == CrashButton::Restart { theme.alert } else { theme.accent })
+                .accelerator(button.accelerator());
+            frame.render_stateful_widget(widget, *button_area, hits);
+        }
+
+        let status = Paragraph::new(Self::status_text(state))
+            .style(Style::default().fg(theme.success))
+            .alignment(Alignment::Center);
+        frame.render_widget(status, layout[4]);
+    }
+
+    fn status_text(state: &CrashState) -> &'static str {
+        match state.copied {
+            Some(true) => t("crash.copied"),
+            Some(false) => t("crash.copy_by_hand"),
+            None => t("crash.footer"),
+        }
+    }
+
+    // Enter restarts, and each button has its letter. For the rest of the
+    // page's life there is no leaving this screen but to restart.
+    pub fn key(press: &KeyPress) -> Option<Msg> {
+        if press.is_shortcut() {
+            return None;
+        }
+
+        let key = press.key.as_str();
+        let button = match key {
+            "Enter" | " " => Some(CrashButton::Restart),
+            _ => CrashButton::ALL
+                .into_iter()
+                .find(|button| key.eq_ignore_ascii_case(&button.accelerator().to_string())),
+        };
+
+        button.map(CrashButton::msg)
+    }
+}
+
+#[cfg(not(feature = "native"))]
+impl CrashScreen {
+    pub fn describe(state: &CrashState) -> Description {
+        CrashButton::ALL
+            .into_iter()
+            .fold(
+                Description::new(t("crash.title")).text(t("crash.message")).text(state.report.text()),
+                |description, button| description.control(Control::button(button.label(), button.msg())),
+            )
+            .status(Self::status_text(state))
+    }
+
+    pub fn listen(listeners: &mut Listeners) -> io::Result<()> {
+        let document = listeners::document()?;
+
+        listeners.add(Scope::App, &document, "keydown", |event: web_sys::KeyboardEvent| {
+            if let Some(msg) = Self::key(&KeyPress::from_event(&event)) {
+                event.prevent_default();
+                app::dispatch(msg);
+            }
+        });
+
+        // Ctrl+C copies the report, also where the clipboard API is missing
+        listeners.add(Scope::App, &document, "copy", |event: web_sys::ClipboardEvent| {
+            let text = app::with_state(|state| state.crash.as_ref().map(|crash| crash.report.text()));
+
+            let (Some(Some(text)), Some(data)) = (text, event.clipboard_data()) else {
+                return;
+            };
+
+            if data.set_data("text/plain", &text).is_ok() {
+                event.prevent_default();
+                app::dispatch(Msg::Crash(CrashMsg::Copied(true)));
+            }
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,188 @@
+// Scripted fall-detection scenario. Time only moves forward through
+// `tick`, so the whole flow can be stepped through without a real clock.
+
+// How long the wearer has to cancel. The device default is 30 s, which
+// drags on a website, so the demo uses a shorter countdown.
+pub const COUNTDOWN_MS: u32 = 10_000;
+
+// Who gets notified, and when, counted from the end of the countdown.
+// Steps are message keys.
+pub const ESCALATION: &[(u32, &str)] = &[
+    (0, "demo.escalation.sms_anna"),
+    (2_000, "demo.escalation.call_anna"),
+    (5_000, "demo.escalation.sms_doctor"),
+];
+
+// When the simulated caregiver picks up
+pub const ACKNOWLEDGED_AFTER_MS: u32 = 8_000;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Alert {
+    #[default]
+    Idle,
+    // "Are you OK?" countdown, any key cancels
+    Countdown { remaining_ms: u32 },
+    // Caregivers are being notified
+    Escalating { elapsed_ms: u32 },
+    // A caregiver confirmed they are on their way
+    Acknowledged,
+    // The wearer cancelled during the countdown
+    Cancelled,
+}
+
+impl Alert {
+    pub fn is_active(&self) -> bool {
+        *self != Alert::Idle
+    }
+
+    pub fn trigger(&mut self) {
+        *self = Alert::Countdown { remaining_ms: COUNTDOWN_MS };
+    }
+
+    // Any key press from the wearer. Returns whether anything changed.
+    pub fn respond(&mut self) -> bool {
+        match self {
+            Alert::Countdown { .. } => *self = Alert::Cancelled,
+            Alert::Acknowledged | Alert::Cancelled => *self = Alert::Idle,
+
+            // Once help is on its way the wearer cannot call it off here
+            Alert::Idle | Alert::Escalating { .. } => return false,
+        }
+
+        true
+    }
+
+    // Advances the scenario. Returns whether anything changed.
+    pub fn tick(&mut self, elapsed_ms: u32) -> bool {
+        match *self {
+            Alert::Countdown { remaining_ms } if remaining_ms > elapsed_ms => {
+                *self = Alert::Countdown { remaining_ms: remaining_ms - elapsed_ms };
+            }
+            // Time left over after the countdown goes to the escalation,
+            // which a long enough step can also see through
+            Alert::Countdown { remaining_ms } => {
+                *self = Alert::Escalating { elapsed_ms: 0 };
+                self.tick(elapsed_ms - remaining_ms);
+            }
+            Alert::Escalating { elapsed_ms: so_far } if so_far + elapsed_ms >= ACKNOWLEDGED_AFTER_MS => {
+                *self = Alert::Acknowledged;
+            }
+            Alert::Escalating { elapsed_ms: so_far } => {
+                *self = Alert::Escalating { elapsed_ms: so_far + elapsed_ms };
+            }
+            Alert::Idle | Alert::Acknowledged | Alert::Cancelled => return false,
+        }
+
+        true
+    }
+
+    // Escalation steps that have happened so far
+    pub fn notified(&self) -> &'static [(u32, &'static str)] {
+        match *self {
+            Alert::Escalating { elapsed_ms } => {
+                let count = ESCALATION.iter().take_while(|(at, _)| *at <= elapsed_ms).count();
+                &ESCALATION[..count]
+            }
+            Alert::Acknowledged => ESCALATION,
+            _ => &[],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MAX_TICK_MS;
+
+    fn countdown() -> Alert {
+        let mut alert = Alert::Idle;
+        alert.trigger();
+        alert
+    }
+
+    #[test]
+    fn countdown_escalates_until_acknowledged() {
+        let mut alert = countdown();
+        assert!(alert.is_active());
+
+        assert!(alert.tick(4_000));
+        assert_eq!(alert, Alert::Countdown { remaining_ms: 6_000 });
+        assert_eq!(alert.notified(), []);
+
+        assert!(alert.tick(6_000));
+        assert_eq!(alert, Alert::Escalating { elapsed_ms: 0 });
+        assert_eq!(alert.notified(), &ESCALATION[..1]);
+
+        assert!(alert.tick(2_000));
+        assert_eq!(alert.notified(), &ESCALATION[..2]);
+
+        assert!(alert.tick(ACKNOWLEDGED_AFTER_MS - 2_000));
+        assert_eq!(alert, Alert::Acknowledged);
+        assert_eq!(alert.notified(), ESCALATION);
+
+        // Nothing moves on its own from here, a key dismisses it
+        assert!(!alert.tick(1_000));
+        assert!(alert.respond());
+        assert_eq!(alert, Alert::Idle);
+    }
+
+    #[test]
+    fn cancelling_during_the_countdown() {
+        let mut alert = countdown();
+        alert.tick(COUNTDOWN_MS - 1);
+
+        assert!(alert.respond());
+        assert_eq!(alert, Alert::Cancelled);
+        assert_eq!(alert.notified(), []);
+        assert!(!alert.tick(COUNTDOWN_MS));
+
+        assert!(alert.respond());
+        assert!(!alert.is_active());
+    }
+
+    #[test]
+    fn escalation_cannot_be_called_off() {
+        let mut alert = countdown();
+        alert.tick(COUNTDOWN_MS + 1_000);
+
+        assert!(!alert.respond());
+        assert_eq!(alert, Alert::Escalating { elapsed_ms: 1_000 });
+    }
+
+    #[test]
+    fn idle_ignores_time_and_keys() {
+        let mut alert = Alert::Idle;
+        assert!(!alert.tick(MAX_TICK_MS as u32));
+        assert!(!alert.respond());
+    }
+
+    #[test]
+    fn long_steps_cross_several_stages() {
+        let mut alert = countdown();
+        alert.tick(COUNTDOWN_MS + 5_000);
+        assert_eq!(alert, Alert::Escalating { elapsed_ms: 5_000 });
+        assert_eq!(alert.notified(), ESCALATION);
+
+        let mut alert = countdown();
+        alert.tick(COUNTDOWN_MS + ACKNOWLEDGED_AFTER_MS);
+        assert_eq!(alert, Alert::Acknowledged);
+    }
+
+    #[test]
+    fn longest_ticks_end_where_short_ones_do() {
+        let step = MAX_TICK_MS as u32;
+        let mut alert = countdown();
+
+        // Just short of the end of the countdown, then one step past it
+        alert.tick(COUNTDOWN_MS - step / 2);
+        alert.tick(step);
+        assert_eq!(alert, Alert::Escalating { elapsed_ms: step / 2 });
+
+        let mut ticks = 0;
+        while alert != Alert::Acknowledged {
+            assert!(alert.tick(step));
+            ticks += 1;
+        }
+        assert_eq!(ticks, (ACKNOWLEDGED_AFTER_MS - step / 2).div_ceil(step));
+    }
+}
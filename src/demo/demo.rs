@@ -1,199 +1,536 @@
 use ratatui::{
-    layout::{Alignment, Constraint, Layout},
-    style::{Color, Style, Stylize},
-    widgets::{Block, Borders, Paragraph},
+    layout::{Alignment, Constraint, Flex, Layout, Rect},
+    style::{Style, Stylize},
+    widgets::{Block, BorderType, Borders, Paragraph},
     text::{Line, Span},
-    Terminal,
+    Frame,
 };
-use wasm_bindgen::prelude::*;
-use wasm_bindgen::JsCast;
-use web_sys::console;
-use std::io;
-use std::cell::RefCell;
+#[cfg(not(feature = "native"))]
+use {
+    std::io,
+    crate::Screen,
+    crate::accessibility::{Control, Description},
+    crate::app,
+    crate::i18n::t_count,
+    crate::listeners::{self, Listeners, Scope},
+    crate::scheduler::Scheduler,
+};
+
 use crate::buttons::ArrowKeys;
+use crate::i18n::{t, t_with};
+use crate::keys::KeyPress;
+use crate::responsive::Breakpoint;
+use crate::state::{Msg, Update};
+use crate::theme::Theme;
+use super::alert::{Alert, COUNTDOWN_MS};
+use super::menu::{MenuNode, DEVICE_MENU};
+
+// How often the demo's scenario timers advance
+pub const TICK_MS: u32 = 100;
+
+// Most recent keys kept in the preferences, the rest are dropped
+const SAVED_KEYS: usize = 50;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DemoState {
+    pub path: Vec<usize>,       // Item indices from the main menu to the open node
+    pub cursor: usize,          // Highlighted item in the open menu
+    pub last_key: Option<ArrowKeys>,
+    pub key_history: Vec<ArrowKeys>,
+    pub alert: Alert,
+}
+
+// Where the visitor got to, kept between visits. An alert in progress
+// is not, a reload ends it like leaving the demo does.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DemoProgress {
+    pub path: Vec<usize>,
+    pub cursor: usize,
+    pub keys: Vec<ArrowKeys>,
+}
+
+impl DemoState {
+    pub fn progress(&self) -> DemoProgress {
+        let skip = self.key_history.len().saturating_sub(SAVED_KEYS);
+
+        DemoProgress {
+            path: self.path.clone(),
+            cursor: self.cursor,
+            keys: self.key_history[skip..].to_vec(),
+        }
+    }
 
-// Global state to persist across re-renders
-thread_local! {
-    static LAST_KEY: RefCell<Option<ArrowKeys>> = RefCell::new(None);
-    static KEY_HISTORY: RefCell<Vec<ArrowKeys>> = RefCell::new(Vec::new());
-    static DEMO_ACTIVE: RefCell<bool> = RefCell::new(false);
-    static LISTENER_ATTACHED: RefCell<bool> = RefCell::new(false);
+    // A path the menu no longer has (it changed since the last visit)
+    // starts over from the main menu
+    pub fn restore(&mut self, progress: &DemoProgress) {
+        let node = DEVICE_MENU.descend(&progress.path);
+        let fits = node.is_some_and(|node| progress.cursor < node.items().len().max(1));
+
+        (self.path, self.cursor) = if fits { (progress.path.clone(), progress.cursor) } else { (Vec::new(), 0) };
+        self.key_history = progress.keys.clone();
+        self.last_key = progress.keys.last().copied();
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DemoMsg {
+    Key(ArrowKeys),
+    Select,
+    Escape,
+    SimulateFall,
+    AnyKey,
+    Tick(u32),
 }
 
 pub struct DemoScreen;
 
 impl DemoScreen {
-    pub fn start<B>(terminal: &mut Terminal<B>) -> io::Result<()>
-    where
-        B: ratatui::backend::Backend,
-    {
-        // Mark demo as active
-        DEMO_ACTIVE.with(|active| {
-            *active.borrow_mut() = true;
-        });
+    pub fn update(state: &mut DemoState, msg: DemoMsg) -> Update {
+        // While an alert is up it takes over the device, every key is an
+        // answer to it
+        if state.alert.is_active() {
+            let changed = match msg {
+                DemoMsg::Tick(elapsed_ms) => state.alert.tick(elapsed_ms),
+                _ => state.alert.respond(),
+            };
+
+            return if changed { Update::Redraw } else { Update::Ignored };
+        }
+
+        match msg {
+            DemoMsg::Key(arrow_key) => {
+                state.last_key = Some(arrow_key);
+                state.key_history.push(arrow_key);
+
+                match arrow_key {
+                    ArrowKeys::TopArrow => Self::move_cursor(state, false),
+                    ArrowKeys::BottomArrow => Self::move_cursor(state, true),
+                    ArrowKeys::RightArrow => Self::open(state),
+                    ArrowKeys::LeftArrow => Self::close(state),
+                }
+
+                Update::Redraw
+            }
+            DemoMsg::Select => {
+                Self::open(state);
+                Update::Redraw
+            }
+
+            // Escape on the main menu leaves the demo, like the device's
+            // back button leaves its menu for the clock face
+            DemoMsg::Escape if state.path.is_empty() => Update::Send(Msg::Back),
+            DemoMsg::Escape => {
+                Self::close(state);
+                Update::Redraw
+            }
+            DemoMsg::SimulateFall => {
+                state.alert.trigger();
+                Update::Redraw
+            }
+            DemoMsg::AnyKey | DemoMsg::Tick(_) => Update::Ignored,
+        }
+    }
 
-        terminal.draw(|frame| {
-            let area = frame.area();
+    fn current(state: &DemoState) -> &'static MenuNode {
+        DEVICE_MENU.descend(&state.path).unwrap_or(&DEVICE_MENU)
+    }
+
+    fn move_cursor(state: &mut DemoState, down: bool) {
+        let count = Self::current(state).items().len();
+        if count == 0 {
+            return;
+        }
+
+        state.cursor = if down {
+            (state.cursor + 1) % count
+        } else {
+            (state.cursor + count - 1) % count
+        };
+    }
+
+    fn open(state: &mut DemoState) {
+        if state.cursor < Self::current(state).items().len() {
+            state.path.push(state.cursor);
+            state.cursor = 0;
+        }
+    }
+
+    // Back up one level, with the entry we came from highlighted again
+    fn close(state: &mut DemoState) {
+        if let Some(index) = state.path.pop() {
+            state.cursor = index;
+        }
+    }
+
+    pub fn render(frame: &mut Frame, area: Rect, state: &DemoState, theme: &Theme) {
+
+        let layout = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Min(10),
+            Constraint::Length(3),
+        ])
+        .split(area);
+
+        // Title
+        let title = Paragraph::new(t("demo.title"))
+            .style(Style::default().fg(theme.title).bold())
+            .alignment(Alignment::Center);
+        frame.render_widget(title, layout[0]);
 
-            let layout = Layout::vertical([
-                Constraint::Length(3),
-                Constraint::Min(10),
-                Constraint::Length(3),
+        let breakpoint = Breakpoint::of(area);
+        let (device_area, controls_area) = Self::body_areas(layout[1], breakpoint);
+
+        frame.render_widget(Self::device(state, theme), device_area);
+        if let Some(controls_area) = controls_area {
+            frame.render_widget(Self::controls(state, theme), controls_area);
+        }
+
+        // Footer
+        let footer_text = match (breakpoint, controls_area) {
+            (_, None) => t("demo.footer.no_controls"),
+            (Breakpoint::Compact, Some(_)) => t("demo.footer.compact"),
+            (Breakpoint::Regular | Breakpoint::Wide, Some(_)) => t("demo.footer"),
+        };
+        let footer = Paragraph::new(footer_text)
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        frame.render_widget(footer, layout[2]);
+    }
+
+    // Device screen next to the controls, or above them on narrow screens.
+    // The controls are left out when there is no room for both.
+    fn body_areas(area: Rect, breakpoint: Breakpoint) -> (Rect, Option<Rect>) {
+        if breakpoint != Breakpoint::Compact {
+            let body = Layout::horizontal([
+                Constraint::Length(40),     // Device
+                Constraint::Length(30),     // Controls
             ])
+            .flex(Flex::Center)
+            .spacing(2)
             .split(area);
 
-            // Title
-            let title = Paragraph::new("AlertAngel - Demo")
-                .style(Style::default().fg(Color::LightYellow).bold())
-                .alignment(Alignment::Center);
-            frame.render_widget(title, layout[0]);
-
-            // Demo content with key display
-            let last_key_display = LAST_KEY.with(|k| {
-                if let Some(key) = *k.borrow() {
-                    format!("{} ({})", key.as_symbol(), key.as_name())
-                } else {
-                    "None".to_string()
+            let device_area = Layout::vertical([Constraint::Length(14)])
+                .flex(Flex::Center)
+                .split(body[0])[0];
+            let controls_area = Layout::vertical([Constraint::Length(14)])
+                .flex(Flex::Center)
+                .split(body[1])[0];
+
+            return (device_area, Some(controls_area));
+        }
+
+        let column = Layout::horizontal([Constraint::Max(40)])
+            .flex(Flex::Center)
+            .split(area)[0];
+
+        if area.height < 28 {
+            let device_area = Layout::vertical([Constraint::Max(14)])
+                .flex(Flex::Center)
+                .split(column)[0];
+            return (device_area, None);
+        }
+
+        let stacked = Layout::vertical([Constraint::Length(14), Constraint::Length(14)])
+            .flex(Flex::Center)
+            .split(column);
+        (stacked[0], Some(stacked[1]))
+    }
+
+    fn device(state: &DemoState, theme: &Theme) -> Paragraph<'static> {
+        if state.alert.is_active() {
+            return Self::alert(state.alert, theme);
+        }
+
+        let node = Self::current(state);
+
+        // Breadcrumb of the open menus
+        let mut titles = vec![DEVICE_MENU.title()];
+        for depth in 1..=state.path.len() {
+            if let Some(node) = DEVICE_MENU.descend(&state.path[..depth]) {
+                titles.push(node.title());
+            }
+        }
+
+        let mut lines = vec![
+            Line::from(Span::styled(titles.join(" › "), Style::default().fg(theme.muted))),
+            Line::from(""),
+        ];
+
+        match node {
+            MenuNode::Menu { items, .. } => {
+                for (index, item) in items.iter().enumerate() {
+                    let submenu = if matches!(item, MenuNode::Menu { .. }) { " ›" } else { "" };
+
+                    if index == state.cursor {
+                        lines.push(Line::from(Span::styled(
+                            format!("▶ {}{}", item.title(), submenu),
+                            Style::default().fg(theme.background).bg(theme.accent).bold(),
+                        )));
+                    } else {
+                        lines.push(Line::from(Span::styled(
+                            format!("  {}{}", item.title(), submenu),
+                            Style::default().fg(theme.text),
+                        )));
+                    }
                 }
-            });
-
-            let history_display = KEY_HISTORY.with(|h| {
-                let history = h.borrow();
-                if history.is_empty() {
-                    "No keys pressed yet".to_string()
-                } else {
-                    history.iter()
-                        .rev()
-                        .take(10)
-                        .map(|k| k.as_symbol())
-                        .collect::<Vec<_>>()
-                        .join(" ")
+            }
+            MenuNode::Page { .. } => {
+                for line in node.lines() {
+                    lines.push(Line::from(Span::styled(line, Style::default().fg(theme.text))));
                 }
-            });
-
-            let content_lines = vec![
-                Line::from(vec![
-                    Span::styled("🎮 Demo Mode", Style::default().fg(Color::Cyan).bold())
-                ]),
-                Line::from(""),
-                Line::from(vec![
-                    Span::styled("Press Arrow Keys to interact", Style::default().fg(Color::White))
-                ]),
-                Line::from(""),
-                Line::from(vec![
-                    Span::styled("Last Key Pressed: ", Style::default().fg(Color::Gray)),
-                    Span::styled(last_key_display, Style::default().fg(Color::Green).bold())
-                ]),
-                Line::from(""),
-                Line::from(vec![
-                    Span::styled("Key History: ", Style::default().fg(Color::Gray)),
-                ]),
-                Line::from(vec![
-                    Span::styled(history_display, Style::default().fg(Color::Magenta))
-                ]),
-                Line::from(""),
-                Line::from(vec![
-                    Span::styled("Arrow Key Controls:", Style::default().fg(Color::Yellow))
-                ]),
-                Line::from(vec![
-                    Span::styled("  ↑ Up  |  ↓ Down  |  ← Left  |  → Right", Style::default().fg(Color::White))
-                ]),
-            ];
-
-            let content = Paragraph::new(content_lines)
-                .block(
-                    Block::default()
-                        .borders(Borders::ALL)
-                        .border_style(Style::default().fg(Color::Cyan))
-                )
-                .alignment(Alignment::Center);
-            
-            frame.render_widget(content, layout[1]);
-
-            // Footer
-            let footer = Paragraph::new("Press ESC to go back to home")
-                .style(Style::default().fg(Color::Gray))
-                .alignment(Alignment::Center);
-            frame.render_widget(footer, layout[2]);
-        })?;
-
-        // Only set up key handler once per demo session
-        LISTENER_ATTACHED.with(|attached| {
-            if !*attached.borrow() {
-                Self::setup_key_handler();
-                *attached.borrow_mut() = true;
-                console::log_1(&"Demo event listener attached".into());
             }
-        });
+        }
 
-        Ok(())
+        Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .title(" AlertAngel ")
+                    .title_alignment(Alignment::Center)
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(theme.accent))
+            )
     }
 
-    fn setup_key_handler() {
-        use web_sys::window;
-        
-        let window = window().expect("no global window");
-        let document = window.document().expect("no document");
-        
-        console::log_1(&"Arrow key handler setup for demo screen".into());
-        
-        let closure = Closure::wrap(Box::new(move |event: web_sys::KeyboardEvent| {
-            // Only handle if demo is active
-            let is_active = DEMO_ACTIVE.with(|active| *active.borrow());
-            if !is_active {
-                return;
+    fn alert(alert: Alert, theme: &Theme) -> Paragraph<'static> {
+        let alarm = Style::default().fg(theme.alert).bold();
+        let calm = Style::default().fg(theme.success).bold();
+        let text = Style::default().fg(theme.text);
+        let hint = Style::default().fg(theme.muted);
+
+        let mut lines = vec![Line::from("")];
+        let mut border_color = theme.alert;
+
+        match alert {
+            Alert::Countdown { remaining_ms } => {
+                let seconds = remaining_ms.div_ceil(1000);
+                let filled = (remaining_ms * 20 / COUNTDOWN_MS) as usize;
+
+                lines.push(Line::from(Span::styled(format!("⚠ {}", t("demo.alert.fall_detected")), alarm)));
+                lines.push(Line::from(""));
+                lines.push(Line::from(Span::styled(t("demo.alert.are_you_ok"), text.bold())));
+                lines.push(Line::from(Span::styled(t("demo.alert.press_to_cancel"), text)));
+                lines.push(Line::from(""));
+                lines.push(Line::from(Span::styled(t_with("demo.alert.calling_in", &[("seconds", &seconds)]), text)));
+                lines.push(Line::from(Span::styled(
+                    format!("{}{}", "█".repeat(filled), "░".repeat(20 - filled)),
+                    Style::default().fg(theme.alert),
+                )));
             }
+            Alert::Escalating { .. } => {
+                lines.push(Line::from(Span::styled(format!("⚠ {}", t("demo.alert.fall_detected")), alarm)));
+                lines.push(Line::from(""));
+                lines.push(Line::from(Span::styled(t("demo.alert.notifying"), text.bold())));
+                lines.push(Line::from(""));
+                for (_, step) in alert.notified() {
+                    lines.push(Line::from(Span::styled(format!("✓ {}", t(step)), text)));
+                }
+            }
+            Alert::Acknowledged => {
+                border_color = theme.success;
 
-            let key = event.key();
-            
-            // Check for ESC key
-            if key == "Escape" {
-                console::log_1(&"ESC pressed, going back to home".into());
-                event.prevent_default();
-                
-                // Mark demo as inactive and reset listener flag
-                DEMO_ACTIVE.with(|active| {
-                    *active.borrow_mut() = false;
-                });
-                
-                LISTENER_ATTACHED.with(|attached| {
-                    *attached.borrow_mut() = false;
-                });
-                
-                // Clear demo state
-                LAST_KEY.with(|k| {
-                    *k.borrow_mut() = None;
-                });
-                
-                KEY_HISTORY.with(|h| {
-                    h.borrow_mut().clear();
+                lines.push(Line::from(Span::styled(format!("✓ {}", t("demo.alert.help_on_the_way")), calm)));
+                lines.push(Line::from(""));
+                lines.push(Line::from(Span::styled(t("menu.contacts.anna"), text.bold())));
+                lines.push(Line::from(Span::styled(t("demo.alert.acknowledged"), text)));
+                lines.push(Line::from(""));
+                lines.push(Line::from(Span::styled(t("demo.alert.dismiss"), hint)));
+            }
+            Alert::Cancelled => {
+                border_color = theme.success;
+
+                lines.push(Line::from(Span::styled(t("demo.alert.glad"), calm)));
+                lines.push(Line::from(""));
+                lines.push(Line::from(Span::styled(t("demo.alert.cancelled"), text)));
+                lines.push(Line::from(Span::styled(t("demo.alert.nobody_notified"), text)));
+                lines.push(Line::from(""));
+                lines.push(Line::from(Span::styled(t("demo.alert.dismiss"), hint)));
+            }
+            Alert::Idle => {}
+        }
+
+        Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .title(" AlertAngel ")
+                    .title_alignment(Alignment::Center)
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(border_color))
+            )
+            .alignment(Alignment::Center)
+    }
+
+    fn controls(state: &DemoState, theme: &Theme) -> Paragraph<'static> {
+        // Key display
+        let last_key_display = if let Some(key) = state.last_key {
+            format!("{} ({})", key.as_symbol(), key.as_name())
+        } else {
+            t("demo.controls.none").to_string()
+        };
+
+        let history_display = if state.key_history.is_empty() {
+            t("demo.controls.no_keys").to_string()
+        } else {
+            state.key_history.iter()
+                .rev()
+                .take(10)
+                .map(|k| k.as_symbol())
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        let content_lines = vec![
+            Line::from(vec![
+                Span::styled(format!("🎮 {}", t("demo.controls.title")), Style::default().fg(theme.accent).bold())
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("↑ ↓       ", Style::default().fg(theme.warning)),
+                Span::styled(t("demo.controls.move"), Style::default().fg(theme.text)),
+            ]),
+            Line::from(vec![
+                Span::styled("→ Enter   ", Style::default().fg(theme.warning)),
+                Span::styled(t("demo.controls.select"), Style::default().fg(theme.text)),
+            ]),
+            Line::from(vec![
+                Span::styled("← Esc     ", Style::default().fg(theme.warning)),
+                Span::styled(t("demo.controls.back"), Style::default().fg(theme.text)),
+            ]),
+            Line::from(vec![
+                Span::styled("F         ", Style::default().fg(theme.warning)),
+                Span::styled(t("demo.controls.fall"), Style::default().fg(theme.text)),
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled(format!("{} ", t("demo.controls.last_key")), Style::default().fg(theme.muted)),
+                Span::styled(last_key_display, Style::default().fg(theme.success).bold())
+            ]),
+            Line::from(vec![
+                Span::styled(format!("{} ", t("demo.controls.history")), Style::default().fg(theme.muted)),
+            ]),
+            Line::from(vec![
+                Span::styled(history_display, Style::default().fg(theme.highlight))
+            ]),
+        ];
+
+        Paragraph::new(content_lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(theme.border))
+            )
+            .alignment(Alignment::Center)
+    }
+
+    // How often the screen wants a tick, and the message for it
+    pub fn ticks() -> (u32, fn(u32) -> Msg) {
+        (TICK_MS, |elapsed_ms| Msg::Demo(DemoMsg::Tick(elapsed_ms)))
+    }
+
+    pub fn key(press: &KeyPress) -> Option<Msg> {
+        let key = press.key.as_str();
+
+        // Check for ESC key
+        if key == "Escape" {
+            log::debug!("ESC pressed");
+            return Some(Msg::Demo(DemoMsg::Escape));
+        }
+
+        if key == "Enter" {
+            return Some(Msg::Demo(DemoMsg::Select));
+        }
+
+        // Check for arrow keys
+        if let Some(arrow_key) = ArrowKeys::from_key_string(key) {
+            return Some(Msg::Demo(DemoMsg::Key(arrow_key)));
+        }
+
+        // Leave browser and system shortcuts alone
+        if press.is_shortcut() {
+            return None;
+        }
+
+        if key.eq_ignore_ascii_case("f") {
+            Some(Msg::Demo(DemoMsg::SimulateFall))
+        } else {
+            Some(Msg::Demo(DemoMsg::AnyKey))
+        }
+    }
+}
+
+#[cfg(not(feature = "native"))]
+impl DemoScreen {
+    pub fn describe(state: &DemoState) -> Description {
+        let description = Description::new(t("demo.title"))
+            .text(t("demo.description"))
+            .status(Self::alert_status(state.alert));
+
+        let description = match state.alert {
+            Alert::Countdown { remaining_ms } => description
+                .heading(t("demo.alert.fall_detected"))
+                .text(t_count("demo.alert.calling_in_full", u64::from(remaining_ms.div_ceil(1000)), &[])),
+            Alert::Escalating { .. } => state.alert.notified().iter().fold(description.heading(t("demo.alert.notifying")), |description, (_, step)| {
+                description.text(t(step))
+            }),
+            Alert::Acknowledged | Alert::Cancelled => description.heading(t("demo.alert.over")).text(t("demo.alert.dismiss")),
+            Alert::Idle => {
+                let node = Self::current(state);
+                let description = node.items().iter().enumerate().fold(description.heading(node.title()), |description, (index, item)| {
+                    let mut entry = vec![item.title()];
+                    if matches!(item, MenuNode::Menu { .. }) {
+                        entry.push(t("demo.menu.submenu"));
+                    }
+                    if index == state.cursor {
+                        entry.push(t("demo.menu.selected"));
+                    }
+                    description.text(entry.join(", "))
                 });
-                
-                let _ = crate::show_home_from_demo();
-                return;
+                node.lines().fold(description, Description::text)
             }
-            
-            // Check for arrow keys
-            if let Some(arrow_key) = ArrowKeys::from_key_string(&key) {
+        };
+
+        description
+            .control(Control::button(t("demo.controls.fall"), Msg::Demo(DemoMsg::SimulateFall)))
+            .control(Control::button(t("app.back"), Msg::Demo(DemoMsg::Escape)))
+    }
+
+    // Said once per stage of the alert, not on every tick of the countdown
+    fn alert_status(alert: Alert) -> &'static str {
+        match alert {
+            Alert::Countdown { .. } => t("demo.status.countdown"),
+            Alert::Escalating { .. } => match alert.notified().last() {
+                Some((_, step)) => t(step),
+                None => t("demo.alert.notifying"),
+            },
+            Alert::Acknowledged => t("demo.status.acknowledged"),
+            Alert::Cancelled => t("demo.status.cancelled"),
+            Alert::Idle => "",
+        }
+    }
+
+    pub fn subscribe(scheduler: &mut Scheduler) {
+        let (interval_ms, tick) = Self::ticks();
+        scheduler.subscribe(Scope::Screen(Screen::Demo), interval_ms, tick);
+    }
+
+    pub fn listen(listeners: &mut Listeners) -> io::Result<()> {
+        let document = listeners::document()?;
+
+        listeners.add(Scope::Screen(Screen::Demo), &document, "keydown", |event: web_sys::KeyboardEvent| {
+            let Some(msg) = Self::key(&KeyPress::from_event(&event)) else {
+                return;
+            };
+
+            // Other keys keep doing what they do in the browser, like
+            // reloading or moving focus
+            if !matches!(msg, Msg::Demo(DemoMsg::SimulateFall | DemoMsg::AnyKey)) {
                 event.prevent_default();
-                
-                // Update global state
-                LAST_KEY.with(|k| {
-                    *k.borrow_mut() = Some(arrow_key);
-                });
-                
-                KEY_HISTORY.with(|h| {
-                    h.borrow_mut().push(arrow_key);
-                });
-                
-                // Re-render the demo screen with updated state
-                let _ = crate::show_demo_screen();
             }
-        }) as Box<dyn FnMut(_)>);
-        
-        document.add_event_listener_with_callback("keydown", closure.as_ref().unchecked_ref())
-            .expect("failed to add keydown listener");
-        
-        closure.forget();
+
+            app::dispatch(msg);
+        });
+
+        Ok(())
     }
 }
@@ -0,0 +1,140 @@
+// The device menu tree. This mirrors the menu in the AlertAngel firmware,
+// so when a menu entry changes on the device it only needs changing here;
+// the demo screen walks whatever tree it is given. Titles and lines are
+// message keys, the text for each language is in `locales/`.
+
+use crate::i18n::t;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuNode {
+    // A list of entries to pick from
+    Menu {
+        title: &'static str,
+        items: &'static [MenuNode],
+    },
+    // A leaf showing read-only information
+    Page {
+        title: &'static str,
+        lines: &'static [&'static str],
+    },
+}
+
+impl MenuNode {
+    pub fn title(&self) -> &'static str {
+        match self {
+            MenuNode::Menu { title, .. } | MenuNode::Page { title, .. } => t(title),
+        }
+    }
+
+    // A page's lines in the active language, nothing for a menu
+    pub fn lines(&self) -> impl Iterator<Item = &'static str> {
+        let lines: &'static [&'static str] = match self {
+            MenuNode::Menu { .. } => &[],
+            MenuNode::Page { lines, .. } => lines,
+        };
+
+        lines.iter().map(|line| t(line))
+    }
+
+    pub fn items(&self) -> &'static [MenuNode] {
+        match self {
+            MenuNode::Menu { items, .. } => items,
+            MenuNode::Page { .. } => &[],
+        }
+    }
+
+    // Follows a list of item indices down from this node
+    pub fn descend(&self, path: &[usize]) -> Option<&MenuNode> {
+        path.iter().try_fold(self, |node, &index| node.items().get(index))
+    }
+}
+
+pub const DEVICE_MENU: MenuNode = MenuNode::Menu {
+    title: "menu.main",
+    items: &[
+        MenuNode::Page {
+            title: "menu.status",
+            lines: &[
+                "menu.status.battery",
+                "menu.status.signal",
+                "menu.status.heart_rate",
+                "menu.status.fall_detection",
+                "menu.status.check_in",
+            ],
+        },
+        MenuNode::Menu {
+            title: "menu.contacts",
+            items: &[
+                MenuNode::Page {
+                    title: "menu.contacts.anna",
+                    lines: &[
+                        "menu.contacts.anna.phone",
+                        "menu.contacts.anna.role",
+                        "menu.contacts.anna.alerts",
+                    ],
+                },
+                MenuNode::Page {
+                    title: "menu.contacts.doctor",
+                    lines: &[
+                        "menu.contacts.doctor.phone",
+                        "menu.contacts.doctor.role",
+                        "menu.contacts.doctor.alerts",
+                    ],
+                },
+                MenuNode::Page {
+                    title: "menu.contacts.emergency",
+                    lines: &[
+                        "menu.contacts.emergency.phone",
+                        "menu.contacts.emergency.role",
+                        "menu.contacts.emergency.alerts",
+                    ],
+                },
+            ],
+        },
+        MenuNode::Menu {
+            title: "menu.settings",
+            items: &[
+                MenuNode::Page {
+                    title: "menu.settings.fall",
+                    lines: &[
+                        "menu.settings.fall.enabled",
+                        "menu.settings.fall.sensitivity",
+                        "menu.settings.fall.countdown",
+                    ],
+                },
+                MenuNode::Page {
+                    title: "menu.settings.reminders",
+                    lines: &[
+                        "menu.settings.reminders.morning",
+                        "menu.settings.reminders.evening",
+                    ],
+                },
+                MenuNode::Page {
+                    title: "menu.settings.display",
+                    lines: &[
+                        "menu.settings.display.brightness",
+                        "menu.settings.display.text_size",
+                    ],
+                },
+                MenuNode::Page {
+                    title: "menu.settings.sound",
+                    lines: &[
+                        "menu.settings.sound.volume",
+                        "menu.settings.sound.tone",
+                    ],
+                },
+            ],
+        },
+        MenuNode::Page {
+            title: "menu.history",
+            lines: &[
+                "menu.history.check_in",
+                "menu.history.check_in.outcome",
+                "menu.history.fall",
+                "menu.history.fall.outcome",
+                "menu.history.battery",
+                "menu.history.battery.outcome",
+            ],
+        },
+    ],
+};
@@ -1 +1,4 @@
+#[allow(clippy::module_inception)]
 pub mod demo;
+pub mod menu;
+pub mod alert;
@@ -0,0 +1,92 @@
+use color_eyre::config::{HookBuilder, Theme as ReportTheme};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Document, Element};
+
+use crate::crash::Report;
+use crate::i18n::t;
+use crate::listeners;
+
+// Routes errors and panics through color-eyre's report format. Without
+// colors, since reports end up in the console and on screen rather than
+// in a terminal that understands them.
+pub fn install() {
+    let (panic_hook, eyre_hook) = HookBuilder::blank()
+        .theme(ReportTheme::new())
+        .display_env_section(false)
+        .into_hooks();
+
+    if let Err(error) = eyre_hook.install() {
+        log::warn!("Failed to install the error report hook: {}", error);
+    }
+
+    // No more Rust runs on the page after a panic, so the app can't show
+    // its error screen; the page gets a plain one instead
+    std::panic::set_hook(Box::new(move |info| {
+        let report = Report {
+            details: panic_hook.panic_report(info).to_string(),
+        };
+        log::error!("{}", report.text());
+        show(&report);
+    }));
+}
+
+// Replaces the whole page, terminal included, with a plain error page.
+// Its buttons are HTML with inline handlers, which work with the app gone.
+pub fn show(report: &Report) {
+    let Ok(document) = listeners::document() else {
+        return;
+    };
+
+    if let Err(error) = build_page(&document, report) {
+        log::error!("Failed to show the error page: {:?}", error);
+    }
+}
+
+fn build_page(document: &Document, report: &Report) -> Result<(), JsValue> {
+    let body = document.body().ok_or("no body")?;
+
+    let page = element(document, "div", "")?;
+    page.set_id("crash");
+    page.set_attribute("role", "alertdialog")?;
+    page.set_attribute("aria-labelledby", "crash-title")?;
+
+    let title = element(document, "h1", t("crash.title"))?;
+    title.set_id("crash-title");
+
+    let message = element(document, "p", t("crash.message"))?;
+
+    let text = element(document, "pre", &report.text())?;
+    text.set_id("crash-report");
+
+    let restart = element(document, "button", t("crash.restart"))?;
+    restart.set_attribute("onclick", "location.reload()")?;
+
+    let copy = element(document, "button", t("crash.copy"))?;
+    copy.set_attribute(
+        "onclick",
+        "navigator.clipboard && navigator.clipboard.writeText(document.getElementById('crash-report').textContent)",
+    )?;
+
+    page.append_child(&title)?;
+    page.append_child(&message)?;
+    page.append_child(&text)?;
+    page.append_child(&restart)?;
+    page.append_child(&copy)?;
+
+    body.set_inner_html("");
+    body.append_child(&page)?;
+
+    if let Some(restart) = restart.dyn_ref::<web_sys::HtmlElement>() {
+        restart.focus()?;
+    }
+
+    Ok(())
+}
+
+fn element(document: &Document, tag: &str, text: &str) -> Result<Element, JsValue> {
+    let element = document.create_element(tag)?;
+    if !text.is_empty() {
+        element.set_text_content(Some(text));
+    }
+    Ok(element)
+}
@@ -0,0 +1,62 @@
+use ratatui::layout::{Position, Rect};
+use wasm_bindgen::JsCast;
+use web_sys::Element;
+
+// Where the terminal grid sits on the page and how big its cells are, in
+// CSS pixels. Measured from the rendered `pre` rows rather than assumed, so
+// it holds for any font size, zoom level or page offset.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Grid {
+    pub left: f64,
+    pub top: f64,
+    pub cell_width: f64,
+    pub cell_height: f64,
+    pub columns: u16,
+    pub rows: u16,
+}
+
+impl Grid {
+    // `area` is the terminal area of the last drawn frame
+    pub fn measure(area: Rect) -> Option<Self> {
+        if area.is_empty() {
+            return None;
+        }
+
+        let document = web_sys::window()?.document()?;
+        let rows = document.query_selector_all("pre").ok()?;
+        if rows.length() == 0 {
+            return None;
+        }
+
+        let first = rows.item(0)?.dyn_into::<Element>().ok()?.get_bounding_client_rect();
+        let last = rows.item(rows.length() - 1)?.dyn_into::<Element>().ok()?.get_bounding_client_rect();
+
+        let grid = Self {
+            left: first.left(),
+            top: first.top(),
+            cell_width: first.width() / f64::from(area.width),
+            cell_height: (last.bottom() - first.top()) / f64::from(rows.length()),
+            columns: area.width,
+            rows: area.height,
+        };
+
+        // Nothing laid out yet (e.g. the page is hidden)
+        if grid.cell_width <= 0.0 || grid.cell_height <= 0.0 {
+            return None;
+        }
+
+        Some(grid)
+    }
+
+    // Terminal cell under a point given in client coordinates
+    pub fn cell_at(&self, x: f64, y: f64) -> Option<Position> {
+        let col = ((x - self.left) / self.cell_width).floor();
+        let row = ((y - self.top) / self.cell_height).floor();
+
+        if col < 0.0 || row < 0.0 || col >= f64::from(self.columns) || row >= f64::from(self.rows) {
+            return None;
+        }
+
+        Some(Position::new(col as u16, row as u16))
+    }
+}
@@ -0,0 +1,43 @@
+use ratatui::layout::{Position, Rect};
+
+use crate::state::Msg;
+
+// Clickable regions registered by widgets during the last draw, and where
+// the pointer currently is on the grid
+#[derive(Debug, Default)]
+pub struct HitMap {
+    regions: Vec<(Rect, Msg)>,
+    pub pointer: Option<Position>,
+    pub pressed: bool,
+}
+
+impl HitMap {
+    // Called at the start of every draw, the pointer is kept
+    pub fn clear(&mut self) {
+        self.regions.clear();
+    }
+
+    pub fn register(&mut self, area: Rect, action: Msg) {
+        self.regions.push((area, action));
+    }
+
+    // Later registrations are drawn on top, so they win
+    pub fn action_at(&self, cell: Position) -> Option<&Msg> {
+        self.regions()
+            .rev()
+            .find(|(area, _)| area.contains(cell))
+            .map(|(_, action)| action)
+    }
+
+    pub fn regions(&self) -> impl DoubleEndedIterator<Item = &(Rect, Msg)> {
+        self.regions.iter()
+    }
+
+    pub fn hovered_action(&self) -> Option<&Msg> {
+        self.pointer.and_then(|cell| self.action_at(cell))
+    }
+
+    pub fn is_hovered(&self, area: Rect) -> bool {
+        self.pointer.is_some_and(|cell| area.contains(cell))
+    }
+}
@@ -1,307 +1,377 @@
 use ratatui::{
-    Terminal, 
+    Frame,
     layout::{
-        Alignment, 
+        Alignment,
         Constraint,
+        Flex,
         Layout,
-        Margin,
         Rect,
-    }, 
+    },
     style::{
-        Color,
         Style,
         Stylize
-    }, 
-    text::{
-        Line,
-        Span
-    }, 
-    widgets::{
-        Block,
-        Borders,
-        Paragraph
-    }
+    },
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+use unicode_width::UnicodeWidthStr;
+#[cfg(not(feature = "native"))]
+use {
+    std::io,
+    crate::accessibility::{Control, Description},
+    crate::app,
+    crate::listeners::{self, Listeners, Scope},
+    crate::theme::ThemeName,
 };
-use wasm_bindgen::prelude::*;
-use wasm_bindgen::JsCast;
-use web_sys::console;
-use std::io;
-use std::cell::RefCell;
+
+use crate::{Screen, ZOOM_SUPPORTED};
+use crate::i18n::{self, t, t_with};
+use crate::blog::posts;
+use crate::buttons::{ArrowKeys, Button};
+use crate::hit_test::HitMap;
+use crate::keys::KeyPress;
+use crate::responsive::{self, Breakpoint};
+use crate::state::{Msg, Update};
+use crate::theme::Theme;
+use crate::zoom::Zoom;
 
 pub struct HomeScreen;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum ButtonState {
-    Normal,
-    Hovered
+pub enum HomeButton {
+    Demo,
+    Blog,
+    Contact,
 }
 
-// Global state to track button states
-thread_local! {
-    static DEMO_HOVERED: RefCell<bool> = RefCell::new(false);
-    static BLOG_HOVERED: RefCell<bool> = RefCell::new(false);
-    static CONTACT_HOVERED: RefCell<bool> = RefCell::new(false);
-    static HOME_ACTIVE: RefCell<bool> = RefCell::new(false);
-}
+impl HomeButton {
+    // Top to bottom, which is also the focus order
+    pub const ALL: [HomeButton; 3] = [HomeButton::Demo, HomeButton::Blog, HomeButton::Contact];
 
-impl HomeScreen {
-    pub fn start<B>(terminal: &mut Terminal<B>) -> io::Result<()> 
-        where 
-            B: ratatui::backend::Backend,
-    {
-        // Mark home as active
-        HOME_ACTIVE.with(|active| {
-            *active.borrow_mut() = true;
-        });
+    pub fn label(self) -> &'static str {
+        match self {
+            HomeButton::Demo => t("home.demo"),
+            HomeButton::Blog => t("home.blog"),
+            HomeButton::Contact => t("home.contact"),
+        }
+    }
 
-        terminal.draw(|frame| {
-            let area = frame.area();
-
-            // Main Layout
-            let main_layout = Layout::vertical([
-                Constraint::Length(6),       // Title
-                Constraint::Min(20),         // Button Area
-                Constraint::Length(3),       // Footer
-            ])
-                .split(area);
-
-            // Title
-            let title = Paragraph::new("AlertAngel")
-                .style(Style::default().fg(Color::LightYellow).bold())
-                .alignment(Alignment::Center);
-
-            frame.render_widget(title, main_layout[0]);
-
-            // Button Layout 
-            let button_layout = Layout::vertical([
-                Constraint::Length(5),      // Demo Button
-                Constraint::Length(1),      // Spacing
-                Constraint::Length(5),      // Blog Button 
-                Constraint::Length(1),      // Spacing
-                Constraint::Length(5),      // Contact Button 
-                Constraint::Min(0),         // Remaining Space
-            ])
-                .split(main_layout[1].inner(Margin {
-                    horizontal: 20,
-                    vertical: 2,
-                }));
-
-            // Demo Button
-            let demo_state = DEMO_HOVERED.with(|h| if *h.borrow() { ButtonState::Hovered } else { ButtonState::Normal });
-            let demo_button = Self::create_button(
-                "Demo", 
-                "Get a feel of the UI and workflow before buying the device",
-                Color::Cyan,
-                demo_state
-            );
-            frame.render_widget(demo_button, button_layout[0]);
-
-            // Blog Button
-            let blog_state = BLOG_HOVERED.with(|h| if *h.borrow() { ButtonState::Hovered } else { ButtonState::Normal });
-            let blog_button = Self::create_button(
-                "Blog",
-                "Coming Soon",
-                Color::Magenta,
-                blog_state,
-            );
-            frame.render_widget(blog_button, button_layout[2]);
-
-            // Contact Button
-            let contact_state = CONTACT_HOVERED.with(|h| if *h.borrow() { ButtonState::Hovered } else { ButtonState::Normal });
-            let contact_button = Self::create_button(
-                "Contact",
-                "Email : lorem@ipsummail.com",
-                Color::Green,
-                contact_state,
-            );
-            frame.render_widget(contact_button, button_layout[4]);
-
-            // Footer 
-            let footer = Paragraph::new("Use keyboard shortcuts or click on buttons to navigate")
-                .style(Style::default().fg(Color::Gray))
-                .alignment(Alignment::Center);
-
-            frame.render_widget(footer, main_layout[2]);
-
-            // Set up event handlers with button areas
-            Self::setup_event_handlers(
-                button_layout[0],
-                button_layout[2],
-                button_layout[4]
-            );
-        })?;
+    pub fn screen(self) -> Screen {
+        match self {
+            HomeButton::Demo => Screen::Demo,
+            HomeButton::Blog => Screen::Blog,
+            HomeButton::Contact => Screen::Contact,
+        }
+    }
 
-        Ok(())
+    pub fn tooltip(self) -> &'static str {
+        match self {
+            _ if !self.screen().available() => t("home.browser_only"),
+            HomeButton::Demo => t("home.demo.tooltip"),
+            HomeButton::Blog if self.is_enabled() => t("home.blog.tooltip"),
+            HomeButton::Blog => t("home.blog.coming_soon"),
+            HomeButton::Contact => t("home.contact.tooltip"),
+        }
     }
 
-    fn setup_event_handlers(demo_area: Rect, blog_area: Rect, contact_area: Rect) {
-        use web_sys::window;
-        
-        let window = window().expect("no global window");
-        let document = window.document().expect("no document");
-        
-        console::log_1(&"Setting up home screen event handlers".into());
-
-        // Click handler
-        let window_for_click = window.clone();
-        let click_closure = Closure::wrap(Box::new(move |event: web_sys::MouseEvent| {
-            // Only handle if home is active
-            let is_active = HOME_ACTIVE.with(|active| *active.borrow());
-            if !is_active {
-                return;
-            }
+    // The first letter of the label, so shortcuts follow the language
+    pub fn accelerator(self) -> char {
+        self.label().chars().next().map_or(' ', |c| c.to_ascii_lowercase())
+    }
 
-            let (col, row) = Self::get_terminal_coords(event.client_x(), event.client_y());
-            
-            console::log_1(&format!("Click at Col: {}, Row: {}", col, row).into());
-            
-            if Self::is_in_area(col, row, demo_area) {
-                console::log_1(&"Demo Button Clicked".into());
-                
-                // Mark home as inactive
-                HOME_ACTIVE.with(|active| {
-                    *active.borrow_mut() = false;
-                });
-                
-                let window_clone = window_for_click.clone();
-                let closure = Closure::once(Box::new(move || {
-                    let _ = crate::show_demo_screen();
-                }) as Box<dyn FnOnce()>);
-                
-                window_clone.set_timeout_with_callback_and_timeout_and_arguments_0(
-                    closure.as_ref().unchecked_ref(),
-                    10
-                ).expect("setTimeout failed");
-                
-                closure.forget();
-            }
-            
-            if Self::is_in_area(col, row, blog_area) {
-                console::log_1(&"Blog Button Clicked (Coming Soon)".into());
-            }
-            
-            if Self::is_in_area(col, row, contact_area) {
-                console::log_1(&"Contact Button Clicked".into());
+    pub fn from_accelerator(key: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|button| key.eq_ignore_ascii_case(&button.accelerator().to_string()))
+    }
+
+    // Blog stays "Coming Soon" until there is a post to show
+    pub fn is_enabled(self) -> bool {
+        match self {
+            _ if !self.screen().available() => false,
+            HomeButton::Demo => true,
+            HomeButton::Blog => !posts::all().is_empty(),
+            HomeButton::Contact => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HomeState {
+    pub focus: Option<HomeButton>,
+    pub confirm_reset: bool,    // Reset asked for once, the next one goes ahead
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "native", allow(dead_code))]
+pub enum HomeMsg {
+    FocusNext,
+    FocusPrevious,
+    Focus(HomeButton),  // From the screen reader mirror, which the terminal build doesn't have
+    ActivateFocused,
+    Activate(HomeButton),
+    ResetPreferences,
+}
+
+impl HomeScreen {
+    pub fn update(state: &mut HomeState, msg: HomeMsg) -> Update {
+        // Anything else in between calls the reset off
+        let confirmed = std::mem::take(&mut state.confirm_reset);
+
+        match msg {
+            HomeMsg::ResetPreferences if confirmed => Update::Send(Msg::ResetPreferences),
+            HomeMsg::ResetPreferences => {
+                state.confirm_reset = true;
+                Update::Redraw
             }
-        }) as Box<dyn FnMut(_)>);
-        
-        document.add_event_listener_with_callback("click", click_closure.as_ref().unchecked_ref())
-            .expect("failed to add click listener");
-        click_closure.forget();
-
-        // Mousemove handler for hover effects
-        let document_clone = document.clone();
-        let mousemove_closure = Closure::wrap(Box::new(move |event: web_sys::MouseEvent| {
-            // Only handle if home is active
-            let is_active = HOME_ACTIVE.with(|active| *active.borrow());
-            if !is_active {
-                return;
+            HomeMsg::FocusNext => Self::move_focus(state, true),
+            HomeMsg::FocusPrevious => Self::move_focus(state, false),
+            HomeMsg::Focus(button) if button.is_enabled() => {
+                state.focus = Some(button);
+                Update::Redraw
             }
+            HomeMsg::Focus(_) => Update::Ignored,
+            HomeMsg::ActivateFocused => match state.focus {
+                Some(button) => Update::Send(Msg::Home(HomeMsg::Activate(button))),
+                None => Update::Ignored,
+            },
+            HomeMsg::Activate(button) if !button.is_enabled() => Update::Ignored,
+            HomeMsg::Activate(button) => Update::Send(Msg::Navigate(button.screen())),
+        }
+    }
 
-            let (col, row) = Self::get_terminal_coords(event.client_x(), event.client_y());
-            
-            let demo_hover = Self::is_in_area(col, row, demo_area);
-            let blog_hover = Self::is_in_area(col, row, blog_area);
-            let contact_hover = Self::is_in_area(col, row, contact_area);
-            
-            let any_hover = demo_hover || blog_hover || contact_hover;
-            
-            // Update cursor style using setAttribute
-            if let Some(body) = document_clone.body() {
-                let cursor_style = if any_hover { "cursor: pointer;" } else { "cursor: default;" };
-                let _ = body.set_attribute("style", cursor_style);
-            }
-            
-            // Update hover states
-            let mut needs_redraw = false;
-            
-            DEMO_HOVERED.with(|h| {
-                if *h.borrow() != demo_hover {
-                    *h.borrow_mut() = demo_hover;
-                    needs_redraw = true;
-                }
-            });
-            
-            BLOG_HOVERED.with(|h| {
-                if *h.borrow() != blog_hover {
-                    *h.borrow_mut() = blog_hover;
-                    needs_redraw = true;
-                }
-            });
-            
-            CONTACT_HOVERED.with(|h| {
-                if *h.borrow() != contact_hover {
-                    *h.borrow_mut() = contact_hover;
-                    needs_redraw = true;
-                }
-            });
-            
-            // Redraw if hover state changed
-            if needs_redraw {
-                let backend = ratzilla::DomBackend::new().unwrap();
-                let mut terminal = ratatui::Terminal::new(backend).unwrap();
-                let _ = Self::start(&mut terminal);
+    // Cycles through the enabled buttons, wrapping around at either end
+    fn move_focus(state: &mut HomeState, forward: bool) -> Update {
+        let buttons: Vec<HomeButton> = HomeButton::ALL
+            .into_iter()
+            .filter(|button| button.is_enabled())
+            .collect();
+
+        let current = state.focus.and_then(|focus| buttons.iter().position(|b| *b == focus));
+        let next = match (current, forward) {
+            (Some(i), true) => (i + 1) % buttons.len(),
+            (Some(i), false) => (i + buttons.len() - 1) % buttons.len(),
+            (None, true) => 0,
+            (None, false) => buttons.len() - 1,
+        };
+
+        state.focus = Some(buttons[next]);
+        Update::Redraw
+    }
+
+    pub fn render(frame: &mut Frame, area: Rect, state: &HomeState, theme: &Theme, zoom: Zoom, hits: &mut HitMap) {
+        let breakpoint = Breakpoint::of(area);
+        let short = responsive::is_short(area);
+
+        // Main Layout
+        let main_layout = Layout::vertical([
+            Constraint::Length(if short { 2 } else { 6 }),  // Title
+            Constraint::Min(0),                             // Button Area
+            Constraint::Length(if short { 1 } else { 3 }),  // Footer
+        ])
+            .split(area);
+
+        // Title
+        let title = Paragraph::new("AlertAngel")
+            .style(Style::default().fg(theme.title).bold())
+            .alignment(Alignment::Center);
+
+        frame.render_widget(title, main_layout[0]);
+
+        let [demo_area, blog_area, contact_area] = Self::button_areas(main_layout[1], breakpoint, short);
+
+        // Demo Button
+        let demo_button = Self::button(state, theme, HomeButton::Demo).color(theme.accent);
+        frame.render_stateful_widget(demo_button, demo_area, hits);
+
+        // Blog Button
+        let blog_button = Self::button(state, theme, HomeButton::Blog).color(theme.highlight);
+        frame.render_stateful_widget(blog_button, blog_area, hits);
+
+        // Contact Button
+        let contact_button = Self::button(state, theme, HomeButton::Contact).color(theme.success);
+        frame.render_stateful_widget(contact_button, contact_area, hits);
+
+        // Footer
+        let footer_text = match breakpoint {
+            Breakpoint::Compact => t("home.footer.compact"),
+            Breakpoint::Regular | Breakpoint::Wide => t("home.footer"),
+        };
+        let footer_rows = Layout::vertical([Constraint::Length(1); 3]).split(main_layout[2]);
+        let footer = Paragraph::new(footer_text)
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+
+        frame.render_widget(footer, footer_rows[0]);
+
+        if !short {
+            let settings = [
+                (t_with("home.theme", &[("theme", &theme.name.label())]), Some(Msg::CycleTheme)),
+                ("   ".to_string(), None),
+                (t_with("home.language", &[("language", &i18n::active().name())]), Some(Msg::CycleLanguage)),
+            ];
+            Self::settings(frame, footer_rows[1], theme, &settings, hits);
+
+            let zoom_action = |msg| ZOOM_SUPPORTED.then_some(msg);
+
+            let text_size = [
+                ("[-]".to_string(), zoom_action(Msg::ZoomOut)),
+                (" ".to_string(), None),
+                (t_with("home.zoom", &[("percent", &zoom.percent())]), zoom_action(Msg::ZoomReset)),
+                (" ".to_string(), None),
+                ("[+]".to_string(), zoom_action(Msg::ZoomIn)),
+                ("   ".to_string(), None),
+                (Self::reset_label(state, "home.reset", "home.reset.confirm"), Some(Msg::Home(HomeMsg::ResetPreferences))),
+            ];
+            Self::settings(frame, footer_rows[2], theme, &text_size, hits);
+        }
+    }
+
+    // A centered line of settings, the parts with an action clickable and
+    // lit up under the pointer
+    fn settings(frame: &mut Frame, area: Rect, theme: &Theme, parts: &[(String, Option<Msg>)], hits: &mut HitMap) {
+        let width = |text: &str| u16::try_from(text.width()).unwrap_or(u16::MAX);
+
+        let total = parts
+            .iter()
+            .fold(0u16, |total, (label, _)| total.saturating_add(width(label)));
+
+        let mut x = area.x + area.width.saturating_sub(total) / 2;
+        let mut spans = Vec::new();
+
+        for (label, action) in parts {
+            let part = Rect::new(x, area.y, width(label), 1).intersection(area);
+
+            if let Some(action) = action {
+                let color = if hits.is_hovered(part) { theme.text } else { theme.muted };
+                spans.push(Span::styled(label.as_str(), Style::default().fg(color)));
+                hits.register(part, action.clone());
+            } else {
+                spans.push(Span::raw(label.as_str()));
             }
-        }) as Box<dyn FnMut(_)>);
-        
-        document.add_event_listener_with_callback("mousemove", mousemove_closure.as_ref().unchecked_ref())
-            .expect("failed to add mousemove listener");
-        mousemove_closure.forget();
+
+            x = x.saturating_add(width(label));
+        }
+
+        let line = Paragraph::new(Line::from(spans))
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+
+        frame.render_widget(line, area);
+    }
+
+    // Side by side when there is room, otherwise stacked. Short windows
+    // get buttons without the tooltip line.
+    fn button_areas(area: Rect, breakpoint: Breakpoint, short: bool) -> [Rect; 3] {
+        let height = if short { 3 } else { 5 };
+        let area = area.inner(breakpoint.margin());
+
+        let row = if breakpoint == Breakpoint::Wide {
+            Layout::horizontal([Constraint::Fill(1); 3])
+                .spacing(2)
+                .split(Layout::vertical([Constraint::Length(height)]).flex(Flex::Center).split(area)[0])
+        } else {
+            Layout::vertical([Constraint::Length(height); 3])
+                .spacing(if short { 0 } else { 1 })
+                .split(area)
+        };
+
+        [row[0], row[1], row[2]]
     }
 
-    fn get_terminal_coords(x: i32, y: i32) -> (u16, u16) {
-        const CHAR_WIDTH: i32 = 10;
-        const CHAR_HEIGHT: i32 = 20;
-        
-        let col = (x / CHAR_WIDTH) as u16;
-        let row = (y / CHAR_HEIGHT) as u16;
-        
-        (col, row)
+    // Asks for a second go before forgetting anything
+    fn reset_label(state: &HomeState, label: &'static str, confirm: &'static str) -> String {
+        t(if state.confirm_reset { confirm } else { label }).to_string()
     }
 
-    fn is_in_area(col: u16, row: u16, area: Rect) -> bool {
-        col >= area.x && col < area.x + area.width
-            && row >= area.y && row < area.y + area.height
+    fn button(state: &HomeState, theme: &Theme, button: HomeButton) -> Button<'static> {
+        Button::new(button.label(), Msg::Home(HomeMsg::Activate(button)))
+            .theme(theme)
+            .tooltip(button.tooltip())
+            .accelerator(button.accelerator())
+            .focused(state.focus == Some(button))
+            .disabled(!button.is_enabled())
     }
 
-    fn create_button<'a>(
-        title: &'a str,
-        tooltip: &'a str,
-        color: Color,
-        state: ButtonState
-    ) -> Paragraph<'a> {
-        let (bg_color, border_color, text_color) = match state {
-            ButtonState::Normal => (Color::Reset, color, Color::White),
-            ButtonState::Hovered => (color, Color::White, Color::Black),
+    pub fn key(press: &KeyPress) -> Option<Msg> {
+        // Leave browser and system shortcuts alone
+        if press.is_shortcut() {
+            return None;
+        }
+
+        let key = press.key.as_str();
+
+        if key.eq_ignore_ascii_case("t") {
+            return Some(Msg::CycleTheme);
+        }
+
+        if key.eq_ignore_ascii_case("l") {
+            return Some(Msg::CycleLanguage);
+        }
+
+        if key.eq_ignore_ascii_case("r") {
+            return Some(Msg::Home(HomeMsg::ResetPreferences));
+        }
+
+        match key {
+            "+" | "=" => return Some(Msg::ZoomIn),
+            "-" => return Some(Msg::ZoomOut),
+            "0" => return Some(Msg::ZoomReset),
+            _ => {}
+        }
+
+        let msg = match key {
+            "Tab" if press.shift => HomeMsg::FocusPrevious,
+            "Tab" => HomeMsg::FocusNext,
+            "Enter" | " " => HomeMsg::ActivateFocused,
+            _ => match ArrowKeys::from_key_string(key) {
+                Some(ArrowKeys::TopArrow | ArrowKeys::LeftArrow) => HomeMsg::FocusPrevious,
+                Some(ArrowKeys::BottomArrow | ArrowKeys::RightArrow) => HomeMsg::FocusNext,
+                None => HomeMsg::Activate(HomeButton::from_accelerator(key)?),
+            },
         };
 
-        let content = vec![
-            Line::from(vec![
-                Span::styled(
-                    format!("  {}  ", title),
-                    Style::default()
-                        .fg(text_color)
-                        .bold()
-                ),
-            ]),
-            Line::from(""),
-            Line::from(vec![
-                Span::styled(
-                    tooltip,
-                    Style::default()
-                        .fg(if state == ButtonState::Hovered { Color::DarkGray } else { Color::Gray })
-                        .italic()
-                ),
-            ]),
-        ];
-
-        Paragraph::new(content)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_style(Style::default().fg(border_color))
-                    .style(Style::default().bg(bg_color))
+        Some(Msg::Home(msg))
+    }
+}
+
+#[cfg(not(feature = "native"))]
+impl HomeScreen {
+    pub fn describe(state: &HomeState, theme: ThemeName, zoom: Zoom) -> Description {
+        let description = HomeButton::ALL.into_iter().fold(Description::new("AlertAngel"), |description, button| {
+            description.control(
+                Control::button(button.label(), Msg::Home(HomeMsg::Activate(button)))
+                    .hint(button.tooltip())
+                    .on_focus(Msg::Home(HomeMsg::Focus(button)))
+                    .focused(state.focus == Some(button))
+                    .disabled(!button.is_enabled()),
             )
-            .alignment(Alignment::Center)
+        });
+
+        let language = i18n::active().name();
+        let text_size = t_with("home.zoom.hint", &[("percent", &zoom.percent())]);
+
+        description
+            .control(Control::button(t_with("home.theme.button", &[("theme", &theme.label())]), Msg::CycleTheme).hint(t("home.theme.hint")))
+            .control(Control::button(t_with("home.language.button", &[("language", &language)]), Msg::CycleLanguage).hint(t("home.language.hint")))
+            .control(Control::button(t("home.zoom.smaller"), Msg::ZoomOut).hint(&text_size).disabled(zoom.is_smallest()))
+            .control(Control::button(t("home.zoom.larger"), Msg::ZoomIn).hint(&text_size).disabled(zoom.is_largest()))
+            .control(
+                Control::button(Self::reset_label(state, "home.reset.button", "home.reset.button.confirm"), Msg::Home(HomeMsg::ResetPreferences))
+                    .hint(t("home.reset.hint")),
+            )
+    }
+
+    pub fn listen(listeners: &mut Listeners) -> io::Result<()> {
+        let document = listeners::document()?;
+
+        listeners.add(Scope::Screen(Screen::Home), &document, "keydown", |event: web_sys::KeyboardEvent| {
+            if let Some(msg) = Self::key(&KeyPress::from_event(&event)) {
+                event.prevent_default();
+                app::dispatch(msg);
+            }
+        });
+
+        Ok(())
     }
 }
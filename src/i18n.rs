@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// `CATALOGS`: (language code, file contents) for every file in `locales/`,
+// generated by build.rs
+include!(concat!(env!("OUT_DIR"), "/locales.rs"));
+
+// Has every message; other catalogs fall back to it for anything missing
+const FALLBACK: &str = "en";
+
+// A message catalog. Files in `locales/` are named by language code and
+// hold one message per line:
+//
+//     # Comment
+//     home.demo = Demo
+//     contact.direct = Or write to us directly at {email}
+//     blog.count.one = {count} post
+//     blog.count.other = {count} posts
+//
+// Values are trimmed, unless quoted like `"  indented"`. `{name}` is
+// filled in by `t_with`. Counted messages have a key per plural category
+// of the language (`one`, `few`, `many`, `other`), see `t_count`.
+#[derive(Debug)]
+pub struct Catalog {
+    pub code: &'static str,
+    messages: HashMap<&'static str, &'static str>,
+}
+
+impl Catalog {
+    fn parse(code: &'static str, source: &'static str) -> Self {
+        let messages = source
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| line.split_once('='))
+            .map(|(key, value)| (key.trim(), unquote(value.trim())))
+            .collect();
+
+        Self { code, messages }
+    }
+
+    fn get(&self, key: &str) -> Option<&'static str> {
+        self.messages.get(key).copied()
+    }
+}
+
+// Every catalog, the fallback first and the rest by language code
+pub fn catalogs() -> &'static [Catalog] {
+    static LOADED: OnceLock<Vec<Catalog>> = OnceLock::new();
+
+    LOADED.get_or_init(|| {
+        let mut catalogs: Vec<Catalog> = CATALOGS
+            .iter()
+            .map(|(code, source)| Catalog::parse(code, source))
+            .collect();
+
+        catalogs.sort_by_key(|catalog| (catalog.code != FALLBACK, catalog.code));
+        catalogs
+    })
+}
+
+// A language the site is translated into, as an index into `catalogs()`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Language(usize);
+
+impl Language {
+    pub fn code(self) -> &'static str {
+        catalogs().get(self.0).map_or(FALLBACK, |catalog| catalog.code)
+    }
+
+    // In the language itself, e.g. "Deutsch"
+    pub fn name(self) -> &'static str {
+        lookup(self, "language.name").unwrap_or(self.code())
+    }
+
+    pub fn next(self) -> Self {
+        Language((self.0 + 1) % catalogs().len().max(1))
+    }
+
+    // A BCP 47 tag like `de-AT`, matched on the language part
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let code = tag.split(['-', '_']).next()?;
+
+        catalogs()
+            .iter()
+            .position(|catalog| catalog.code.eq_ignore_ascii_case(code))
+            .map(Language)
+    }
+
+    // First of the browser's preferred languages that there is a catalog
+    // for, which applies until the visitor picks one
+    #[cfg(not(feature = "native"))]
+    pub fn detect() -> Option<Self> {
+        let navigator = web_sys::window()?.navigator();
+
+        navigator
+            .languages()
+            .iter()
+            .filter_map(|tag| tag.as_string())
+            .chain(navigator.language())
+            .find_map(|tag| Self::from_tag(&tag))
+    }
+
+    // The terminal's locale, e.g. `LANG=de_DE.UTF-8`
+    #[cfg(feature = "native")]
+    pub fn detect() -> Option<Self> {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .into_iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find_map(|tag| Self::from_tag(&tag))
+    }
+}
+
+// Messages are looked up on every draw from all over the app, so the
+// language in use is kept here rather than passed down to every screen.
+// `AppState::language` stays the source of truth; the app activates it
+// before drawing.
+static ACTIVE: AtomicUsize = AtomicUsize::new(0);
+
+pub fn activate(language: Language) {
+    ACTIVE.store(language.0, Ordering::Relaxed);
+}
+
+pub fn active() -> Language {
+    Language(ACTIVE.load(Ordering::Relaxed))
+}
+
+// The message for `key` in the active language, falling back to English
+// and then to the key itself, so a missing message shows up on screen
+pub fn t(key: &'static str) -> &'static str {
+    lookup(active(), key)
+        .or_else(|| lookup(Language::default(), key))
+        .unwrap_or(key)
+}
+
+// `t` with `{name}` placeholders filled in
+pub fn t_with(key: &'static str, args: &[(&str, &dyn Display)]) -> String {
+    fill(t(key), args)
+}
+
+// A message that depends on a number, from the key for the number's
+// plural category (`key.one`, `key.other`, ...) with `{count}` filled in
+#[cfg(not(feature = "native"))]
+pub fn t_count(key: &'static str, count: u64, args: &[(&str, &dyn Display)]) -> String {
+    let language = active();
+    let category = plural_category(language.code(), count);
+
+    let message = [language, Language::default()]
+        .into_iter()
+        .find_map(|language| {
+            lookup(language, &format!("{}.{}", key, category))
+                .or_else(|| lookup(language, &format!("{}.other", key)))
+        })
+        .unwrap_or(key);
+
+    let count: &dyn Display = &count;
+    let mut all_args = vec![("count", count)];
+    all_args.extend_from_slice(args);
+    fill(message, &all_args)
+}
+
+// CLDR plural rules for cardinal numbers, for the languages with catalogs
+#[cfg(not(feature = "native"))]
+fn plural_category(code: &str, count: u64) -> &'static str {
+    match code {
+        "fr" if count <= 1 => "one",
+        "fr" => "other",
+        _ if count == 1 => "one",
+        _ => "other",
+    }
+}
+
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .unwrap_or(value)
+}
+
+fn lookup(language: Language, key: &str) -> Option<&'static str> {
+    catalogs().get(language.0)?.get(key)
+}
+
+fn fill(message: &str, args: &[(&str, &dyn Display)]) -> String {
+    args.iter().fold(message.to_string(), |message, (name, value)| {
+        message.replace(&format!("{{{}}}", name), &value.to_string())
+    })
+}
@@ -0,0 +1,54 @@
+use crate::state::Msg;
+
+// A key press as the browser names it (`KeyboardEvent.key` and `.code`),
+// whichever backend it came from, so screens handle keys the same way in
+// the browser and in the terminal build
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyPress {
+    pub key: String,    // What the key types, or its name: "a", "Enter", "ArrowUp"
+    pub code: String,   // The physical key: "KeyA", "Equal"
+    pub shift: bool,
+    pub alt: bool,
+    pub ctrl: bool,     // Control, or Command on a Mac
+    pub meta: bool,     // Command on its own, which is also `ctrl`
+}
+
+impl KeyPress {
+    #[cfg(not(feature = "native"))]
+    pub fn from_event(event: &web_sys::KeyboardEvent) -> Self {
+        Self {
+            key: event.key(),
+            code: event.code(),
+            shift: event.shift_key(),
+            alt: event.alt_key(),
+            ctrl: event.ctrl_key() || event.meta_key(),
+            meta: event.meta_key(),
+        }
+    }
+
+    // Browser and system shortcuts, which screens leave alone
+    pub fn is_shortcut(&self) -> bool {
+        self.ctrl || self.alt
+    }
+}
+
+// The Alt chords, shared with the terminal build. By physical key, Alt
+// turns letters into symbols on some layouts.
+pub fn shortcut(press: &KeyPress) -> Option<Msg> {
+    if !press.alt || press.shift {
+        return None;
+    }
+
+    let msg = match press.code.as_str() {
+        "ArrowLeft" => Msg::Back,
+        "ArrowRight" => Msg::Forward,
+        "KeyT" => Msg::CycleTheme,
+        "KeyL" => Msg::CycleLanguage,
+        "Equal" | "NumpadAdd" => Msg::ZoomIn,
+        "Minus" | "NumpadSubtract" => Msg::ZoomOut,
+        "Digit0" | "Numpad0" => Msg::ZoomReset,
+        _ => return None,
+    };
+
+    Some(msg)
+}
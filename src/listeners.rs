@@ -0,0 +1,155 @@
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{Event, EventTarget};
+use std::io;
+
+use crate::Screen;
+
+// The targets most listeners go on. Missing only outside a browser page
+// (in a worker, say), which the app cannot run in.
+pub fn window() -> io::Result<web_sys::Window> {
+    web_sys::window().ok_or_else(|| io::Error::other("no global window"))
+}
+
+pub fn document() -> io::Result<web_sys::Document> {
+    window()?.document().ok_or_else(|| io::Error::other("no document"))
+}
+
+// Who a listener belongs to. Screen listeners are detached when the screen
+// is left, app listeners live as long as the page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scope {
+    App,
+    Screen(Screen),
+}
+
+struct Listener {
+    scope: Scope,
+    target: EventTarget,
+    event_type: &'static str,
+    capture: bool,
+    attached: bool,
+    closure: Closure<dyn FnMut(Event)>,
+}
+
+impl Listener {
+    fn attach(&mut self) {
+        let result = self.target.add_event_listener_with_callback_and_bool(
+            self.event_type,
+            self.closure.as_ref().unchecked_ref(),
+            self.capture,
+        );
+
+        match result {
+            Ok(()) => self.attached = true,
+            Err(_) => log::warn!("Failed to add {} listener", self.event_type),
+        }
+    }
+
+    fn detach(&mut self) {
+        let result = self.target.remove_event_listener_with_callback_and_bool(
+            self.event_type,
+            self.closure.as_ref().unchecked_ref(),
+            self.capture,
+        );
+
+        match result {
+            Ok(()) => self.attached = false,
+            Err(_) => log::warn!("Failed to remove {} listener", self.event_type),
+        }
+    }
+}
+
+// Owns every DOM listener closure in the app. There is at most one listener
+// per scope, target, event type and phase; adding it again is a no-op.
+//
+// Detached closures are kept around and re-attached the next time their
+// screen is entered. They are never dropped, since the handler that causes
+// a screen to be left is usually still running at that point.
+#[derive(Default)]
+pub struct Listeners {
+    entries: Vec<Listener>,
+}
+
+// How many listeners are attached, by scope, and how many are waiting to be
+// re-attached. More than one per screen element means something stacked up.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ListenerCounts {
+    pub app: usize,
+    pub screen: usize,
+    pub detached: usize,
+}
+
+impl Listeners {
+    pub fn add<E, F>(&mut self, scope: Scope, target: &EventTarget, event_type: &'static str, handler: F)
+    where
+        E: JsCast,
+        F: FnMut(E) + 'static,
+    {
+        self.insert(scope, target, event_type, false, handler);
+    }
+
+    // Like `add`, but runs before any bubbling listeners see the event
+    pub fn add_capture<E, F>(&mut self, scope: Scope, target: &EventTarget, event_type: &'static str, handler: F)
+    where
+        E: JsCast,
+        F: FnMut(E) + 'static,
+    {
+        self.insert(scope, target, event_type, true, handler);
+    }
+
+    pub fn counts(&self) -> ListenerCounts {
+        self.entries.iter().fold(ListenerCounts::default(), |mut counts, listener| {
+            match (listener.attached, listener.scope) {
+                (false, _) => counts.detached += 1,
+                (true, Scope::App) => counts.app += 1,
+                (true, Scope::Screen(_)) => counts.screen += 1,
+            }
+            counts
+        })
+    }
+
+    pub fn detach(&mut self, scope: Scope) {
+        for listener in self.entries.iter_mut() {
+            if listener.scope == scope && listener.attached {
+                listener.detach();
+            }
+        }
+    }
+
+    fn insert<E, F>(&mut self, scope: Scope, target: &EventTarget, event_type: &'static str, capture: bool, mut handler: F)
+    where
+        E: JsCast,
+        F: FnMut(E) + 'static,
+    {
+        let existing = self.entries.iter_mut().find(|listener| {
+            listener.scope == scope
+                && listener.event_type == event_type
+                && listener.capture == capture
+                && JsValue::from(&listener.target) == JsValue::from(target)
+        });
+
+        if let Some(listener) = existing {
+            if !listener.attached {
+                listener.attach();
+            }
+            return;
+        }
+
+        let closure = Closure::wrap(Box::new(move |event: Event| {
+            handler(event.unchecked_into::<E>());
+        }) as Box<dyn FnMut(_)>);
+
+        let mut listener = Listener {
+            scope,
+            target: target.clone(),
+            event_type,
+            capture,
+            attached: false,
+            closure,
+        };
+
+        listener.attach();
+        self.entries.push(listener);
+    }
+}
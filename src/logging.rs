@@ -0,0 +1,199 @@
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::RwLock;
+use web_sys::console;
+
+// Everything while developing, and only warnings and errors in release
+// builds, so visitors' consoles stay quiet
+const DEFAULT_LEVEL: LevelFilter = if cfg!(debug_assertions) { LevelFilter::Debug } else { LevelFilter::Warn };
+
+// Module paths start with the crate's name, which targets leave out
+const CRATE_PREFIX: &str = concat!(env!("CARGO_CRATE_NAME"), "::");
+
+// Levels from the page URL, `?log=debug` for every target, or per target
+// (a module path) with the most specific match winning:
+//
+//     ?log=info,demo:trace,listeners:off
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Filters {
+    level: LevelFilter,     // For targets without a level of their own
+    targets: Vec<(String, LevelFilter)>,
+}
+
+impl Filters {
+    const fn new() -> Self {
+        Self {
+            level: DEFAULT_LEVEL,
+            targets: Vec::new(),
+        }
+    }
+
+    // Parts that don't parse are skipped and handed back, the rest still
+    // apply
+    fn parse(spec: &str) -> (Self, Vec<&str>) {
+        let mut filters = Self::new();
+        let mut skipped = Vec::new();
+
+        for part in spec.split(',').map(str::trim).filter(|part| !part.is_empty()) {
+            // The level is after the last colon, targets have `::` in them
+            let parsed = match part.rsplit_once(':') {
+                Some((target, level)) => level.parse().map(|level| filters.targets.push((target.to_string(), level))),
+                None => part.parse().map(|level| filters.level = level),
+            };
+
+            if parsed.is_err() {
+                skipped.push(part);
+            }
+        }
+
+        (filters, skipped)
+    }
+
+    fn level_for(&self, target: &str) -> LevelFilter {
+        let target = short_target(target);
+
+        self.targets
+            .iter()
+            .filter(|(prefix, _)| {
+                target
+                    .strip_prefix(prefix.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map_or(self.level, |(_, level)| *level)
+    }
+
+    // The `log` macros skip formatting anything above this
+    fn max_level(&self) -> LevelFilter {
+        self.targets.iter().map(|(_, level)| *level).fold(self.level, Ord::max)
+    }
+}
+
+// Writes records to the browser console, each level to the console method
+// of the same name, so the console's own level filter works on them too
+struct ConsoleLogger {
+    filters: RwLock<Filters>,
+    debugging: AtomicBool,  // Switched on with Alt+Shift+D, see `toggle`
+}
+
+static LOGGER: ConsoleLogger = ConsoleLogger {
+    filters: RwLock::new(Filters::new()),
+    debugging: AtomicBool::new(false),
+};
+
+impl ConsoleLogger {
+    fn level_for(&self, target: &str) -> LevelFilter {
+        let level = self.filters.read().map_or(DEFAULT_LEVEL, |filters| filters.level_for(target));
+        self.with_debugging(level)
+    }
+
+    fn max_level(&self) -> LevelFilter {
+        let level = self.filters.read().map_or(DEFAULT_LEVEL, |filters| filters.max_level());
+        self.with_debugging(level)
+    }
+
+    // Debugging raises every target to at least `Debug`, and leaves the
+    // ones the URL asked more of alone
+    fn with_debugging(&self, level: LevelFilter) -> LevelFilter {
+        if self.debugging.load(Ordering::Relaxed) {
+            level.max(LevelFilter::Debug)
+        } else {
+            level
+        }
+    }
+}
+
+impl Log for ConsoleLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level_for(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let line = format!("[{} {}] {}", record.level(), short_target(record.target()), record.args()).into();
+
+        match record.level() {
+            Level::Error => console::error_1(&line),
+            Level::Warn => console::warn_1(&line),
+            Level::Info => console::info_1(&line),
+            Level::Debug | Level::Trace => console::debug_1(&line),
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+fn short_target(target: &str) -> &str {
+    target.strip_prefix(CRATE_PREFIX).unwrap_or(target)
+}
+
+// Routes the `log` macros to the console, with levels from the page URL's
+// `log` parameter
+pub fn init(query: &str) {
+    // Decoded, since browsers and link shorteners send `demo%3Atrace`
+    let spec = web_sys::UrlSearchParams::new_with_str(query)
+        .ok()
+        .and_then(|params| params.get("log"))
+        .unwrap_or_default();
+    let (filters, skipped) = Filters::parse(&spec);
+
+    if let Ok(mut current) = LOGGER.filters.write() {
+        *current = filters;
+    }
+
+    if log::set_logger(&LOGGER).is_ok() {
+        log::set_max_level(LOGGER.max_level());
+    }
+
+    // Only now that there is somewhere to say it
+    for part in skipped {
+        log::warn!("Ignoring `{}` in ?log=, expected a level or target:level", part);
+    }
+}
+
+// Alt+Shift+D: debug logging for every target, for a visitor to turn on
+// while reproducing a problem, then off again
+pub fn toggle() {
+    let debugging = !LOGGER.debugging.load(Ordering::Relaxed);
+
+    // Said while debug logging is on, so both are seen
+    if !debugging {
+        log::info!("Debug logging off");
+    }
+
+    LOGGER.debugging.store(debugging, Ordering::Relaxed);
+    log::set_max_level(LOGGER.max_level());
+
+    if debugging {
+        log::info!("Debug logging on");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levels_by_target() {
+        let (filters, skipped) = Filters::parse("info, demo:trace,demo::alert:off");
+
+        assert_eq!(skipped, Vec::<&str>::new());
+        assert_eq!(filters.level_for("website::home"), LevelFilter::Info);
+        assert_eq!(filters.level_for("website::demo::demo"), LevelFilter::Trace);
+        assert_eq!(filters.level_for("website::demo::alert"), LevelFilter::Off);
+        assert_eq!(filters.level_for("website::demonstration"), LevelFilter::Info);
+        assert_eq!(filters.max_level(), LevelFilter::Trace);
+    }
+
+    #[test]
+    fn unparsed_parts_are_handed_back() {
+        let (filters, skipped) = Filters::parse("loud,demo:trace,home:,,demo:debug:x");
+
+        assert_eq!(skipped, ["loud", "home:", "demo:debug:x"]);
+        assert_eq!(filters.level, DEFAULT_LEVEL);
+        assert_eq!(filters.targets, [("demo".to_string(), LevelFilter::Trace)]);
+    }
+}
@@ -1,104 +1,126 @@
-use std::io;
-use ratatui::Terminal;
-use ratzilla::DomBackend;
-use wasm_bindgen::prelude::*;
-use web_sys;
+#[cfg(not(feature = "native"))]
+use color_eyre::eyre;
 
+// The terminal build (`--features native`) shares the screens and leaves
+// out the browser glue: the DOM, the URL, the console and the error page
+#[cfg(not(feature = "native"))]
+mod accessibility;
+#[cfg(not(feature = "native"))]
+mod app;
+mod crash;
+#[cfg(not(feature = "native"))]
+mod error_page;
+#[cfg(not(feature = "native"))]
+mod grid;
+mod state;
+#[cfg(not(feature = "native"))]
+mod listeners;
+#[cfg(not(feature = "native"))]
+mod logging;
+mod hit_test;
+#[cfg(not(feature = "native"))]
+mod scheduler;
 mod startup;
 mod home;
+mod i18n;
+mod keys;
 mod demo;
+mod blog;
+mod contact;
 mod buttons;
+mod responsive;
+#[cfg(not(feature = "native"))]
+mod router;
+mod text_input;
+mod markdown;
+#[cfg(feature = "native")]
+mod native;
+#[cfg(not(feature = "native"))]
+mod overlay;
+mod preferences;
+mod storage;
+mod theme;
+mod zoom;
+
+#[cfg(not(feature = "native"))]
+use app::App;
+#[cfg(not(feature = "native"))]
+use crash::Report;
+#[cfg(not(feature = "native"))]
+use preferences::Preferences;
+#[cfg(not(feature = "native"))]
+use startup::{SplashConfig, StartupState};
+#[cfg(not(feature = "native"))]
+use state::AppState;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Screen {
     Startup,
     Home,
     Demo,
+    Blog,
+    Post(usize),    // Index into `blog::posts::all()`
+    Contact,
 }
 
-fn main() -> io::Result<()> {
-    show_startup()?;
-    
-    let window = web_sys::window().expect("no global window exists");
-    
-    let closure = Closure::once(move || {
-        let _ = show_home();
-    });
-    
-    window
-        .set_timeout_with_callback_and_timeout_and_arguments_0(
-            closure.as_ref().unchecked_ref(),
-            2000
-        )
-        .expect("should register timeout");
-    
-    closure.forget();
-
-    Ok(())
+impl Screen {
+    // Whether this build can show the screen. The blog and the contact form
+    // need a browser, the terminal build refuses them and disables their
+    // buttons.
+    pub fn available(self) -> bool {
+        match self {
+            Screen::Startup | Screen::Home | Screen::Demo => true,
+            Screen::Blog | Screen::Post(_) | Screen::Contact => !cfg!(feature = "native"),
+        }
+    }
 }
 
-fn show_startup() -> io::Result<()> {
-    let backend = DomBackend::new()?;
-    let mut terminal = Terminal::new(backend)?;
-    startup::StartupScreen::start(&mut terminal);
-    Ok(())
-}
+// Whether the text size can be changed. In the terminal it is the
+// terminal's own.
+pub const ZOOM_SUPPORTED: bool = !cfg!(feature = "native");
 
-fn show_home() -> io::Result<()> {
-    let backend = DomBackend::new()?;
-    let mut terminal = Terminal::new(backend)?;
-    terminal.clear()?;
-    home::HomeScreen::start(&mut terminal)?;
-    Ok(())
-}
+// Longest step a single tick may report. Browsers stop animation frames in
+// background tabs, and a countdown should resume rather than jump ahead.
+pub const MAX_TICK_MS: f64 = 1000.0;
 
-#[wasm_bindgen]
-pub fn show_demo_screen() -> Result<(), JsValue> {
-    web_sys::console::log_1(&"show_demo_screen called".into());
-    
-    let backend = DomBackend::new().map_err(|e| {
-        let msg = format!("Backend error: {}", e);
-        web_sys::console::log_1(&msg.clone().into());
-        JsValue::from_str(&msg)
-    })?;
-    
-    web_sys::console::log_1(&"Backend created".into());
-    
-    let mut terminal = Terminal::new(backend).map_err(|e| {
-        let msg = format!("Terminal error: {}", e);
-        web_sys::console::log_1(&msg.clone().into());
-        JsValue::from_str(&msg)
-    })?;
-    
-    web_sys::console::log_1(&"Terminal created".into());
-    
-    terminal.clear().map_err(|e| {
-        let msg = format!("Clear error: {}", e);
-        web_sys::console::log_1(&msg.clone().into());
-        JsValue::from_str(&msg)
-    })?;
-    
-    web_sys::console::log_1(&"Terminal cleared".into());
-    
-    crate::demo::demo::DemoScreen::start(&mut terminal).map_err(|e| {
-        let msg = format!("Demo screen error: {}", e);
-        web_sys::console::log_1(&msg.clone().into());
-        JsValue::from_str(&msg)
-    })?;
-    
-    web_sys::console::log_1(&"Demo screen rendered".into());
-    
-    Ok(())
+#[cfg(feature = "native")]
+fn main() -> std::io::Result<()> {
+    native::run()
 }
 
-#[wasm_bindgen]
-pub fn show_home_from_demo() -> Result<(), JsValue> {
-    web_sys::console::log_1(&"Returning to home screen".into());
-    
-    let backend = DomBackend::new().map_err(|e| JsValue::from_str(&e.to_string()))?;
-    let mut terminal = Terminal::new(backend).map_err(|e| JsValue::from_str(&e.to_string()))?;
-    terminal.clear().map_err(|e| JsValue::from_str(&e.to_string()))?;
-    home::HomeScreen::start(&mut terminal).map_err(|e| JsValue::from_str(&e.to_string()))?;
-    
-    Ok(())
+#[cfg(not(feature = "native"))]
+fn main() {
+    let query = web_sys::window()
+        .and_then(|window| window.location().search().ok())
+        .unwrap_or_default();
+
+    // First, so anything that goes wrong from here on is logged and reported
+    logging::init(&query);
+    error_page::install();
+
+    let splash = SplashConfig::from_query(&query);
+
+    let mut preferences = Preferences::load();
+
+    // Links to a particular screen go straight there, without the splash
+    let start = match router::deep_link() {
+        Some(screen) => screen,
+        None if splash.should_show(preferences.visited) => {
+            preferences.visited = true;
+            preferences.save();
+            Screen::Startup
+        }
+        None => Screen::Home,
+    };
+
+    let mut state = AppState::new(start);
+    state.startup = StartupState::new(splash.duration_ms);
+    state.restore(preferences);
+
+    // Without a terminal there is no error screen either, only the page
+    if let Err(error) = App::init(state, overlay::requested(&query)) {
+        let report = Report::from_error(&eyre::Report::new(error).wrap_err("Failed to start"));
+        log::error!("{}", report.text());
+        error_page::show(&report);
+    }
 }
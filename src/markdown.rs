@@ -0,0 +1,710 @@
+use pulldown_cmark::{Alignment, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+#[cfg(not(feature = "native"))]
+use crate::accessibility::Description;
+use crate::i18n::t;
+use crate::theme::Theme;
+
+// Markdown to styled text, already wrapped to `width` columns so it can go
+// straight into a `Paragraph` without `.wrap()`. Covers the CommonMark
+// blocks and inlines plus tables, strikethrough and task lists. Raw HTML
+// is dropped.
+pub fn render(source: &str, width: u16, theme: &Theme) -> Text<'static> {
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+
+    let mut writer = Writer::new(usize::from(width.max(1)), *theme);
+    for event in Parser::new_ext(source, options) {
+        writer.event(event);
+    }
+
+    Text::from(writer.lines)
+}
+
+// The same Markdown as headings and paragraphs of plain text, added to a
+// screen reader description. List items and table rows are paragraphs of
+// their own.
+#[cfg(not(feature = "native"))]
+pub fn describe(source: &str, description: Description) -> Description {
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+
+    let mut description = description;
+    let mut text = String::new();
+
+    for event in Parser::new_ext(source, options) {
+        match event {
+            Event::Text(t) | Event::Code(t) | Event::InlineMath(t) | Event::DisplayMath(t) => text.push_str(&t),
+            Event::SoftBreak | Event::HardBreak => text.push(' '),
+            Event::TaskListMarker(done) => {
+                text.push_str(t(if done { "markdown.done" } else { "markdown.to_do" }));
+                text.push(' ');
+            }
+            Event::End(TagEnd::TableCell) => text.push_str(", "),
+            Event::End(TagEnd::Heading(_)) => {
+                description = description.heading(text.trim());
+                text.clear();
+            }
+            Event::End(TagEnd::Paragraph | TagEnd::Item | TagEnd::CodeBlock | TagEnd::TableHead | TagEnd::TableRow) => {
+                let paragraph = text.trim().trim_end_matches(',');
+                if !paragraph.is_empty() {
+                    description = description.text(paragraph);
+                }
+                text.clear();
+            }
+            _ => {}
+        }
+    }
+
+    description
+}
+
+// Bullets for each level of nesting, repeating past the last
+const BULLETS: [&str; 3] = ["• ", "◦ ", "▪ "];
+
+// Blocks that indent everything inside them
+enum Container {
+    Quote,
+    // The marker is shown on the first line of the item only
+    Item { marker: Option<String>, width: usize },
+}
+
+struct Table {
+    alignments: Vec<Alignment>,
+    rows: Vec<Vec<Vec<Span<'static>>>>,
+    row: Vec<Vec<Span<'static>>>,
+    has_header: bool,
+}
+
+struct Writer {
+    width: usize,
+    theme: Theme,
+    lines: Vec<Line<'static>>,
+    // Inline content of the block being read
+    spans: Vec<Span<'static>>,
+    styles: Vec<Style>,
+    containers: Vec<Container>,
+    // Next number for ordered lists, `None` for bullet lists
+    lists: Vec<Option<u64>>,
+    // Link target and where its text starts in `spans`
+    link: Option<(String, usize)>,
+    table: Option<Table>,
+    in_code_block: bool,
+}
+
+impl Writer {
+    fn new(width: usize, theme: Theme) -> Self {
+        Self {
+            width,
+            theme,
+            lines: Vec::new(),
+            spans: Vec::new(),
+            styles: Vec::new(),
+            containers: Vec::new(),
+            lists: Vec::new(),
+            link: None,
+            table: None,
+            in_code_block: false,
+        }
+    }
+
+    fn event(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) if self.in_code_block => self.code_block(&text),
+            Event::Text(text) => self.text(&text, self.style()),
+            Event::Code(code) | Event::InlineMath(code) | Event::DisplayMath(code) => {
+                self.text(&code, self.style().patch(self.code_style()));
+            }
+            Event::SoftBreak => self.text(" ", self.style()),
+            Event::HardBreak => self.flush(),
+            Event::Rule => {
+                self.flush();
+                self.gap();
+                let rule = "─".repeat(self.available());
+                self.push(vec![Span::styled(rule, self.faint())]);
+            }
+            Event::TaskListMarker(done) => {
+                let marker = if done { "[x] " } else { "[ ] " };
+                self.text(marker, Style::default().fg(self.theme.highlight));
+            }
+            Event::FootnoteReference(name) => self.text(&format!("[^{name}]"), self.style()),
+            Event::Html(_) | Event::InlineHtml(_) => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph => {
+                self.flush();
+                self.gap();
+            }
+            Tag::Heading { level, .. } => {
+                self.flush();
+                self.gap();
+                self.styles.push(self.heading_style(level));
+            }
+            Tag::BlockQuote(_) => {
+                self.flush();
+                self.gap();
+                self.containers.push(Container::Quote);
+                self.styles.push(Style::default().fg(self.theme.muted).add_modifier(Modifier::ITALIC));
+            }
+            Tag::CodeBlock(_) => {
+                self.flush();
+                self.gap();
+                self.in_code_block = true;
+            }
+            Tag::List(start) => {
+                self.flush();
+                if self.lists.is_empty() {
+                    self.gap();
+                }
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                self.flush();
+                let depth = self.lists.len().saturating_sub(1);
+                let marker = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}. ", *number - 1)
+                    }
+                    _ => BULLETS[depth % BULLETS.len()].to_string(),
+                };
+                let width = marker.width();
+                self.containers.push(Container::Item { marker: Some(marker), width });
+            }
+            Tag::Table(alignments) => {
+                self.flush();
+                self.gap();
+                self.table = Some(Table {
+                    alignments,
+                    rows: Vec::new(),
+                    row: Vec::new(),
+                    has_header: false,
+                });
+            }
+            Tag::TableHead | Tag::TableRow => {
+                if let Some(table) = self.table.as_mut() {
+                    table.row.clear();
+                }
+            }
+            Tag::TableCell => self.spans.clear(),
+            Tag::Emphasis => self.styles.push(Style::default().add_modifier(Modifier::ITALIC)),
+            Tag::Strong => self.styles.push(Style::default().add_modifier(Modifier::BOLD)),
+            Tag::Strikethrough => self.styles.push(Style::default().add_modifier(Modifier::CROSSED_OUT)),
+            Tag::Link { dest_url, .. } => {
+                self.link = Some((dest_url.to_string(), self.spans.len()));
+                self.styles.push(self.link_style());
+            }
+            Tag::Image { .. } => {
+                self.text(&format!("[{}: ", t("markdown.image")), self.faint());
+                self.styles.push(Style::default().add_modifier(Modifier::ITALIC));
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => self.flush(),
+            TagEnd::Heading(_) => {
+                self.flush();
+                self.styles.pop();
+            }
+            TagEnd::BlockQuote(_) => {
+                self.flush();
+                self.containers.pop();
+                self.styles.pop();
+            }
+            TagEnd::CodeBlock => self.in_code_block = false,
+            TagEnd::List(_) => {
+                self.flush();
+                self.lists.pop();
+            }
+            TagEnd::Item => {
+                self.flush();
+                self.containers.pop();
+            }
+            TagEnd::Table => {
+                if let Some(table) = self.table.take() {
+                    self.table_lines(table);
+                }
+            }
+            TagEnd::TableHead | TagEnd::TableRow => {
+                if let Some(table) = self.table.as_mut() {
+                    table.has_header |= tag == TagEnd::TableHead;
+                    table.rows.push(std::mem::take(&mut table.row));
+                }
+            }
+            TagEnd::TableCell => {
+                let cell = std::mem::take(&mut self.spans);
+                if let Some(table) = self.table.as_mut() {
+                    table.row.push(cell);
+                }
+            }
+            TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough => {
+                self.styles.pop();
+            }
+            TagEnd::Link => {
+                self.styles.pop();
+
+                // Show where the link goes unless the text already says it
+                if let Some((url, from)) = self.link.take() {
+                    let text: String = self.spans.get(from..).unwrap_or_default().iter().map(|span| span.content.as_ref()).collect();
+                    if text != url && !url.starts_with('#') {
+                        self.text(&format!(" ({url})"), self.faint());
+                    }
+                }
+            }
+            TagEnd::Image => {
+                self.styles.pop();
+                self.text("]", self.faint());
+            }
+            _ => {}
+        }
+    }
+
+    fn heading_style(&self, level: HeadingLevel) -> Style {
+        let style = Style::default().add_modifier(Modifier::BOLD);
+        match level {
+            HeadingLevel::H1 => style.fg(self.theme.title).add_modifier(Modifier::UNDERLINED),
+            HeadingLevel::H2 => style.fg(self.theme.accent),
+            _ => style,
+        }
+    }
+
+    fn code_style(&self) -> Style {
+        Style::default().fg(self.theme.code)
+    }
+
+    fn link_style(&self) -> Style {
+        Style::default().fg(self.theme.link).add_modifier(Modifier::UNDERLINED)
+    }
+
+    fn faint(&self) -> Style {
+        Style::default().fg(self.theme.faint)
+    }
+
+    fn style(&self) -> Style {
+        self.styles.iter().fold(Style::default(), |style, patch| style.patch(*patch))
+    }
+
+    fn text(&mut self, text: &str, style: Style) {
+        self.spans.push(Span::styled(text.to_string(), style));
+    }
+
+    // Columns left once containers have indented the line
+    fn available(&self) -> usize {
+        let indent: usize = self
+            .containers
+            .iter()
+            .map(|container| match container {
+                Container::Quote => 2,
+                Container::Item { width, .. } => *width,
+            })
+            .sum();
+
+        self.width.saturating_sub(indent).max(1)
+    }
+
+    // Container indentation for the next line. The first line of a list
+    // item takes its marker, blank lines leave it for the content.
+    fn prefix(&mut self, blank: bool) -> Vec<Span<'static>> {
+        let bar = self.faint();
+        let marker_style = Style::default().fg(self.theme.highlight);
+
+        self.containers
+            .iter_mut()
+            .map(|container| match container {
+                Container::Quote => Span::styled("│ ", bar),
+                Container::Item { marker, width } => match (blank, marker.take()) {
+                    (false, Some(marker)) => Span::styled(marker, marker_style),
+                    (true, Some(pending)) => {
+                        *marker = Some(pending);
+                        Span::raw(" ".repeat(*width))
+                    }
+                    (_, None) => Span::raw(" ".repeat(*width)),
+                },
+            })
+            .collect()
+    }
+
+    fn push(&mut self, content: Vec<Span<'static>>) {
+        let mut spans = self.prefix(false);
+        spans.extend(content);
+        self.lines.push(Line::from(spans));
+    }
+
+    // One blank line between blocks, none at the top or right after a
+    // list marker that has not been shown yet
+    fn gap(&mut self) {
+        let pending_marker = self
+            .containers
+            .iter()
+            .any(|container| matches!(container, Container::Item { marker: Some(_), .. }));
+        let after_blank = self.lines.last().is_none_or(is_blank);
+
+        if !pending_marker && !after_blank {
+            let prefix = self.prefix(true);
+            self.lines.push(Line::from(prefix));
+        }
+    }
+
+    // Wraps the inline content read so far into lines
+    fn flush(&mut self) {
+        // Table cells collect their content until the table is done
+        if self.spans.is_empty() || self.table.is_some() {
+            return;
+        }
+
+        let spans = std::mem::take(&mut self.spans);
+        for line in wrap(spans, self.available()) {
+            self.push(line);
+        }
+    }
+
+    // Code keeps its line breaks and spacing, long lines are broken anywhere
+    fn code_block(&mut self, code: &str) {
+        let width = self.available().saturating_sub(2).max(1);
+        for line in code.lines() {
+            for chunk in break_anywhere(vec![Span::styled(line.to_string(), self.code_style())], width) {
+                let mut indented = vec![Span::raw("  ")];
+                indented.extend(chunk);
+                self.push(indented);
+            }
+        }
+    }
+
+    fn table_lines(&mut self, table: Table) {
+        let columns = table.rows.iter().map(Vec::len).max().unwrap_or(0);
+        if columns == 0 {
+            return;
+        }
+
+        let mut widths = vec![0; columns];
+        for row in &table.rows {
+            for (column, cell) in row.iter().enumerate() {
+                widths[column] = widths[column].max(spans_width(cell));
+            }
+        }
+
+        // Shrink the widest columns until the table fits, cells get cut
+        let separators = 3 * (columns - 1);
+        let available = self.available().saturating_sub(separators);
+        while widths.iter().sum::<usize>() > available {
+            let Some(widest) = widths.iter_mut().max() else {
+                break;
+            };
+            if *widest <= 1 {
+                break;
+            }
+            *widest -= 1;
+        }
+
+        let border = self.faint();
+        for (index, row) in table.rows.iter().enumerate() {
+            let header = table.has_header && index == 0;
+            let mut line = Vec::new();
+
+            for (column, width) in widths.iter().enumerate() {
+                if column > 0 {
+                    line.push(Span::styled(" │ ", border));
+                }
+
+                let mut cell = truncate(row.get(column).cloned().unwrap_or_default(), *width);
+                if header {
+                    cell = cell
+                        .into_iter()
+                        .map(|span| Span::styled(span.content, span.style.add_modifier(Modifier::BOLD)))
+                        .collect();
+                }
+
+                let padding = width - spans_width(&cell);
+                let alignment = table.alignments.get(column).copied().unwrap_or(Alignment::None);
+                let (left, right) = match alignment {
+                    Alignment::Right => (padding, 0),
+                    Alignment::Center => (padding / 2, padding - padding / 2),
+                    Alignment::Left | Alignment::None => (0, padding),
+                };
+
+                line.push(Span::raw(" ".repeat(left)));
+                line.extend(cell);
+                line.push(Span::raw(" ".repeat(right)));
+            }
+            self.push(line);
+
+            if header {
+                let rule: Vec<String> = widths.iter().map(|width| "─".repeat(*width)).collect();
+                self.push(vec![Span::styled(rule.join("─┼─"), border)]);
+            }
+        }
+    }
+}
+
+fn is_blank(line: &Line) -> bool {
+    line.spans.iter().all(|span| span.content.trim().is_empty() || span.content.trim() == "│")
+}
+
+fn spans_width(spans: &[Span]) -> usize {
+    spans.iter().map(Span::width).sum()
+}
+
+// Greedy word wrap that keeps styles. A word may be split over several
+// spans (`**bold**er`); only words longer than a line are broken.
+fn wrap(spans: Vec<Span<'static>>, width: usize) -> Vec<Vec<Span<'static>>> {
+    let mut lines = Vec::new();
+    let mut line: Vec<Span<'static>> = Vec::new();
+    let mut word: Vec<Span<'static>> = Vec::new();
+    // Style of the space before `word`, spaces inside a link stay underlined
+    let mut space = Style::default();
+
+    let mut place = |line: &mut Vec<Span<'static>>, word: Vec<Span<'static>>, space: Style| {
+        let word_width = spans_width(&word);
+        if word_width == 0 {
+            return;
+        }
+
+        let line_width = spans_width(line);
+        if line_width > 0 && line_width + 1 + word_width <= width {
+            line.push(Span::styled(" ", space));
+            line.extend(word);
+            return;
+        }
+
+        if line_width > 0 {
+            lines.push(std::mem::take(line));
+        }
+
+        let mut pieces = break_anywhere(word, width);
+        *line = pieces.pop().unwrap_or_default();
+        lines.extend(pieces);
+    };
+
+    for span in spans {
+        let mut rest = span.content.as_ref();
+        while let Some(first) = rest.chars().next() {
+            let is_space = first.is_whitespace();
+            let end = rest.find(|c: char| c.is_whitespace() != is_space).unwrap_or(rest.len());
+            let (piece, tail) = rest.split_at(end);
+            rest = tail;
+
+            if is_space {
+                place(&mut line, std::mem::take(&mut word), space);
+                space = span.style;
+            } else {
+                word.push(Span::styled(piece.to_string(), span.style));
+            }
+        }
+    }
+    place(&mut line, word, space);
+
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+// Splits styled text into lines of at most `width` columns
+fn break_anywhere(spans: Vec<Span<'static>>, width: usize) -> Vec<Vec<Span<'static>>> {
+    let mut lines = Vec::new();
+    let mut line = Vec::new();
+    let mut line_width = 0;
+
+    for span in spans {
+        let mut piece = String::new();
+        for c in span.content.chars() {
+            let char_width = c.width().unwrap_or(0);
+            if line_width + char_width > width && line_width > 0 {
+                if !piece.is_empty() {
+                    line.push(Span::styled(std::mem::take(&mut piece), span.style));
+                }
+                lines.push(std::mem::take(&mut line));
+                line_width = 0;
+            }
+            piece.push(c);
+            line_width += char_width;
+        }
+        if !piece.is_empty() {
+            line.push(Span::styled(piece, span.style));
+        }
+    }
+
+    lines.push(line);
+    lines
+}
+
+// Cuts styled text to `width` columns, marking the cut with an ellipsis
+fn truncate(spans: Vec<Span<'static>>, width: usize) -> Vec<Span<'static>> {
+    if spans_width(&spans) <= width {
+        return spans;
+    }
+
+    let mut cut = Vec::new();
+    let mut remaining = width.saturating_sub(1);
+    let mut style = Style::default();
+
+    'spans: for span in spans {
+        style = span.style;
+        let mut piece = String::new();
+        for c in span.content.chars() {
+            let char_width = c.width().unwrap_or(0);
+            if char_width > remaining {
+                cut.push(Span::styled(piece, span.style));
+                break 'spans;
+            }
+            remaining -= char_width;
+            piece.push(c);
+        }
+        cut.push(Span::styled(piece, span.style));
+    }
+
+    if width > 0 {
+        cut.push(Span::styled("…", style));
+    }
+    cut
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(source: &str, width: u16) -> Vec<String> {
+        render(source, width, &Theme::default())
+            .lines
+            .iter()
+            .map(|line| plain(&line.spans).trim_end().to_string())
+            .collect()
+    }
+
+    fn plain(spans: &[Span]) -> String {
+        spans.iter().map(|span| span.content.as_ref()).collect()
+    }
+
+    fn raw(text: &str) -> Vec<Span<'static>> {
+        vec![Span::raw(text.to_string())]
+    }
+
+    fn wrapped(text: &str, width: usize) -> Vec<String> {
+        wrap(raw(text), width).iter().map(|line| plain(line)).collect()
+    }
+
+    fn broken(text: &str, width: usize) -> Vec<String> {
+        break_anywhere(raw(text), width).iter().map(|line| plain(line)).collect()
+    }
+
+    fn truncated(text: &str, width: usize) -> String {
+        plain(&truncate(raw(text), width))
+    }
+
+    #[test]
+    fn headings_and_emphasis() {
+        let source = "# Title\n\nSome *soft* and **strong** text.";
+        assert_eq!(lines(source, 40), ["Title", "", "Some soft and strong text."]);
+
+        let text = render(source, 40, &Theme::default());
+        let title = text.lines[0].spans[0].style;
+        assert!(title.add_modifier.contains(Modifier::BOLD | Modifier::UNDERLINED));
+
+        let soft = text.lines[2].spans.iter().find(|span| span.content == "soft").map(|span| span.style);
+        assert!(soft.is_some_and(|style| style.add_modifier.contains(Modifier::ITALIC)));
+    }
+
+    #[test]
+    fn nested_lists() {
+        let source = "- one\n- two\n  - inner\n\n1. first\n2. second";
+        assert_eq!(lines(source, 40), ["• one", "• two", "  ◦ inner", "", "1. first", "2. second"]);
+    }
+
+    #[test]
+    fn list_items_wrap_under_their_text() {
+        assert_eq!(lines("- one two three", 10), ["• one two", "  three"]);
+    }
+
+    #[test]
+    fn block_quotes() {
+        assert_eq!(lines("> quoted *text*\n> more\n\nafter", 40), ["│ quoted text more", "", "after"]);
+        assert_eq!(lines("> one two three", 10), ["│ one two", "│ three"]);
+    }
+
+    #[test]
+    fn fenced_code_keeps_its_lines() {
+        let source = "```rust\nfn main() {}\n    indented\nabcdefghijklmnopqrstu\n```";
+        assert_eq!(lines(source, 16), ["  fn main() {}", "      indented", "  abcdefghijklmn", "  opqrstu"]);
+    }
+
+    #[test]
+    fn tables_size_columns_to_fit() {
+        let source = "| a | long header |\n|:-|-:|\n| 1 | 2 |";
+        assert_eq!(lines(source, 40), ["a │ long header", "──┼────────────", "1 │           2"]);
+
+        // The widest column gives way, and its cells are cut
+        assert_eq!(lines(source, 10), ["a │ long …", "──┼───────", "1 │      2"]);
+    }
+
+    #[test]
+    fn links_show_where_they_go() {
+        let source = "[docs](https://x.io) and <https://y.io> and [top](#top)";
+        assert_eq!(lines(source, 80), ["docs (https://x.io) and https://y.io and top"]);
+    }
+
+    #[test]
+    fn wrap_breaks_at_spaces() {
+        assert_eq!(wrapped("aaa bbb ccc", 7), ["aaa bbb", "ccc"]);
+        assert_eq!(wrapped("aaa  bbb", 20), ["aaa bbb"]);
+        assert_eq!(wrapped("abcdefghij", 4), ["abcd", "efgh", "ij"]);
+        assert_eq!(wrapped("", 4), Vec::<String>::new());
+    }
+
+    #[test]
+    fn wrap_keeps_styles_within_a_word() {
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let lines = wrap(vec![Span::styled("bold", bold), Span::raw("er next")], 20);
+
+        assert_eq!(lines.len(), 1);
+        assert_eq!(plain(&lines[0]), "bolder next");
+        assert_eq!(lines[0][0].style, bold);
+        assert_eq!(lines[0][1].style, Style::default());
+    }
+
+    #[test]
+    fn wrap_counts_wide_characters() {
+        assert_eq!(wrapped("日本語 テキスト", 6), ["日本語", "テキス", "ト"]);
+        assert_eq!(wrapped("日本語 テキスト", 20), ["日本語 テキスト"]);
+    }
+
+    #[test]
+    fn wrap_without_room_puts_a_character_on_each_line() {
+        assert_eq!(wrapped("ab cd", 1), ["a", "b", "c", "d"]);
+        assert_eq!(wrapped("ab cd", 0), ["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn break_anywhere_splits_by_columns() {
+        assert_eq!(broken("abcdefg", 3), ["abc", "def", "g"]);
+        assert_eq!(broken("日本語", 3), ["日", "本", "語"]);
+        assert_eq!(broken("a日b", 2), ["a", "日", "b"]);
+
+        // Never an empty line, even where a character doesn't fit
+        assert_eq!(broken("日本", 1), ["日", "本"]);
+        assert_eq!(broken("ab", 0), ["a", "b"]);
+        assert_eq!(broken("", 3), [""]);
+    }
+
+    #[test]
+    fn truncate_marks_the_cut() {
+        assert_eq!(truncated("hello", 5), "hello");
+        assert_eq!(truncated("hello", 4), "hel…");
+        assert_eq!(truncated("hello", 1), "…");
+        assert_eq!(truncated("hello", 0), "");
+
+        // A wide character that would cross the edge is left out
+        assert_eq!(truncated("日本語", 4), "日…");
+        assert_eq!(truncated("日本語", 6), "日本語");
+    }
+}
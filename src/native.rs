@@ -0,0 +1,296 @@
+use ratatui::crossterm::event::{
+    self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers,
+    MouseButton, MouseEvent, MouseEventKind,
+};
+use ratatui::crossterm::execute;
+use ratatui::{layout::Position, style::Style, widgets::Block, DefaultTerminal};
+use std::io::{self, stdout};
+use std::time::{Duration, Instant};
+
+use crate::{Screen, MAX_TICK_MS, ZOOM_SUPPORTED};
+use crate::blog::blog::{BlogScreen, PostScreen};
+use crate::blog::posts;
+use crate::contact::ContactScreen;
+use crate::crash::CrashScreen;
+use crate::demo::demo::DemoScreen;
+use crate::hit_test::HitMap;
+use crate::home::HomeScreen;
+use crate::i18n;
+use crate::keys::{self, KeyPress};
+use crate::preferences::Preferences;
+
+use crate::startup::{SplashConfig, StartupMsg, StartupScreen, StartupState};
+use crate::state::{AppState, Effect, Msg};
+
+// How long to wait for input while nothing is ticking
+const IDLE: Duration = Duration::from_millis(250);
+
+// The terminal build: the startup, home and demo screens in a real
+// terminal through crossterm, for comparing the demo with the device side
+// by side and working on the UI without a wasm toolchain.
+//
+//     cargo run --features native -- splash=never
+//
+// Arguments are the page URL's splash settings. Keys and clicks go through
+// the same handlers as in the browser, and Ctrl+C quits. The blog and the
+// contact form need a browser, so their buttons are disabled here; text
+// size is the terminal's own.
+pub fn run() -> io::Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let splash = SplashConfig::from_query(&args.join("&"));
+
+    let start = if splash.should_show(false) { Screen::Startup } else { Screen::Home };
+    let mut state = AppState::new(start);
+    state.startup = StartupState::new(splash.duration_ms);
+    state.restore(Preferences::load());
+
+    let mut terminal = ratatui::init();
+    execute!(stdout(), EnableMouseCapture)?;
+
+    let result = Native::new(state).run(&mut terminal);
+
+    execute!(stdout(), DisableMouseCapture)?;
+    ratatui::restore();
+    result
+}
+
+// The screen's tick subscription, the scheduler's job in the browser
+struct Ticker {
+    interval: Duration,
+    tick: fn(u32) -> Msg,
+    last: Instant,
+}
+
+impl Ticker {
+    fn for_screen(screen: Screen) -> Option<Self> {
+        let (interval_ms, tick) = match screen {
+            Screen::Startup => StartupScreen::ticks(),
+            Screen::Demo => DemoScreen::ticks(),
+            _ => return None,
+        };
+
+        Some(Self {
+            interval: Duration::from_millis(u64::from(interval_ms)),
+            tick,
+            last: Instant::now(),
+        })
+    }
+}
+
+struct Native {
+    state: AppState,
+    hits: HitMap,
+    ticker: Option<Ticker>,
+    dirty: bool,    // Something changed since the last draw
+    quit: bool,
+}
+
+impl Native {
+    fn new(state: AppState) -> Self {
+        let ticker = Ticker::for_screen(state.screen());
+
+        Self {
+            state,
+            hits: HitMap::default(),
+            ticker,
+            dirty: true,
+            quit: false,
+        }
+    }
+
+    fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        while !self.quit {
+            if self.dirty {
+                self.draw(terminal)?;
+            }
+
+            let timeout = self
+                .ticker
+                .as_ref()
+                .map_or(IDLE, |ticker| ticker.interval.saturating_sub(ticker.last.elapsed()));
+
+            if event::poll(timeout)? {
+                match event::read()? {
+                    Event::Key(key) => self.key(key),
+                    Event::Mouse(mouse) => self.mouse(mouse),
+                    Event::Resize(_, _) => self.dispatch(Msg::Resize),
+                    _ => {}
+                }
+            }
+
+            let due = self.ticker.as_mut().and_then(|ticker| {
+                let elapsed = ticker.last.elapsed();
+                if elapsed < ticker.interval {
+                    return None;
+                }
+
+                ticker.last = Instant::now();
+                Some((ticker.tick)((elapsed.as_secs_f64() * 1000.0).min(MAX_TICK_MS) as u32))
+            });
+
+            if let Some(msg) = due {
+                self.dispatch(msg);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn dispatch(&mut self, msg: Msg) {
+        // What this build can't do is refused before the update, the home
+        // screen disables its controls for it as well
+        let refused = match msg {
+            Msg::ZoomIn | Msg::ZoomOut | Msg::ZoomReset => !ZOOM_SUPPORTED,
+            Msg::Navigate(screen) | Msg::Replace(screen) => !screen.available(),
+            _ => false,
+        };
+        if refused {
+            return;
+        }
+
+        let from = self.state.screen();
+        self.dirty |= self.state.update(msg);
+
+        // There is no browser history to follow, nor a page for the other
+        // effects
+        self.state.nav.take_steps();
+        if self.state.take_effects().contains(&Effect::SavePreferences) {
+            self.state.preferences.save();
+        }
+
+        let to = self.state.screen();
+        if to != from {
+            self.ticker = Ticker::for_screen(to);
+            self.dirty = true;
+        }
+    }
+
+    fn key(&mut self, event: KeyEvent) {
+        if event.kind != KeyEventKind::Press {
+            return;
+        }
+
+        if event.modifiers.contains(KeyModifiers::CONTROL) && event.code == KeyCode::Char('c') {
+            self.quit = true;
+            return;
+        }
+
+        let Some(press) = key_press(event) else {
+            return;
+        };
+
+        // App chords first, as the browser's capture listener has them,
+        // unless a field takes the keys as text
+        let shortcut = if self.state.is_typing() { None } else { keys::shortcut(&press) };
+        let msg = shortcut.or_else(|| match self.state.screen() {
+            _ if self.state.crash.is_some() => CrashScreen::key(&press),
+            Screen::Startup => StartupScreen::key(&press),
+            Screen::Home => HomeScreen::key(&press),
+            Screen::Demo => DemoScreen::key(&press),
+            Screen::Blog => BlogScreen::key(&press),
+            Screen::Post(_) => PostScreen::key(&press),
+            Screen::Contact => ContactScreen::key(&press),
+        });
+
+        if let Some(msg) = msg {
+            self.dispatch(msg);
+        }
+    }
+
+    // Cells are the terminal's own, no measuring needed
+    fn mouse(&mut self, event: MouseEvent) {
+        let before = (self.hits.hovered_action().cloned(), self.hits.pressed);
+        self.hits.pointer = Some(Position::new(event.column, event.row));
+
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => self.hits.pressed = true,
+            MouseEventKind::Up(MouseButton::Left) => {
+                self.hits.pressed = false;
+
+                let action = match self.state.screen() {
+                    Screen::Startup => Some(Msg::Startup(StartupMsg::Skip)),
+                    _ => self.hits.hovered_action().cloned(),
+                };
+                if let Some(action) = action {
+                    self.dispatch(action);
+                }
+            }
+            _ => {}
+        }
+
+        self.dirty |= before != (self.hits.hovered_action().cloned(), self.hits.pressed);
+    }
+
+    fn draw(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        i18n::activate(self.state.language);
+
+        let state = &self.state;
+        let theme = state.theme.theme();
+        let hits = &mut self.hits;
+        hits.clear();
+
+        let completed = terminal.draw(|frame| {
+            // Paint every cell, so no terminal default shows through
+            let background = Block::default().style(Style::default().fg(theme.text).bg(theme.background));
+            frame.render_widget(background, frame.area());
+
+            let area = frame.area();
+            match (&state.crash, state.screen()) {
+                (Some(crash), _) => CrashScreen::render(frame, area, crash, theme, hits),
+                (None, Screen::Startup) => StartupScreen::render(frame, area, &state.startup, theme),
+                (None, Screen::Home) => HomeScreen::render(frame, area, &state.home, theme, state.zoom, hits),
+                (None, Screen::Demo) => DemoScreen::render(frame, area, &state.demo, theme),
+                (None, Screen::Blog) => BlogScreen::render(frame, area, &state.blog, theme, hits),
+                (None, Screen::Post(index)) => PostScreen::render(frame, area, &state.post, theme, posts::all().get(index)),
+                (None, Screen::Contact) => ContactScreen::render(frame, area, &state.contact, theme, hits),
+            }
+        })?;
+
+        self.state.size = completed.area.as_size();
+        self.dirty = false;
+        Ok(())
+    }
+}
+
+// Names the key the way the browser would, so the screens' key handlers
+// work unchanged. `None` for keys none of them use.
+fn key_press(event: KeyEvent) -> Option<KeyPress> {
+    let (key, code) = match event.code {
+        KeyCode::Char(c) => (c.to_string(), char_code(c)),
+        KeyCode::Enter => ("Enter".to_string(), "Enter".to_string()),
+        KeyCode::Esc => ("Escape".to_string(), "Escape".to_string()),
+        KeyCode::Tab | KeyCode::BackTab => ("Tab".to_string(), "Tab".to_string()),
+        KeyCode::Backspace => ("Backspace".to_string(), "Backspace".to_string()),
+        KeyCode::Up => ("ArrowUp".to_string(), "ArrowUp".to_string()),
+        KeyCode::Down => ("ArrowDown".to_string(), "ArrowDown".to_string()),
+        KeyCode::Left => ("ArrowLeft".to_string(), "ArrowLeft".to_string()),
+        KeyCode::Right => ("ArrowRight".to_string(), "ArrowRight".to_string()),
+        KeyCode::Home => ("Home".to_string(), "Home".to_string()),
+        KeyCode::End => ("End".to_string(), "End".to_string()),
+        KeyCode::PageUp => ("PageUp".to_string(), "PageUp".to_string()),
+        KeyCode::PageDown => ("PageDown".to_string(), "PageDown".to_string()),
+        _ => return None,
+    };
+
+    Some(KeyPress {
+        key,
+        code,
+        shift: event.modifiers.contains(KeyModifiers::SHIFT) || event.code == KeyCode::BackTab,
+        alt: event.modifiers.contains(KeyModifiers::ALT),
+        ctrl: event.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::SUPER),
+        meta: event.modifiers.contains(KeyModifiers::SUPER),
+    })
+}
+
+// The physical key for a character on a US layout, which the Alt chords
+// are matched by
+fn char_code(c: char) -> String {
+    match c {
+        'a'..='z' | 'A'..='Z' => format!("Key{}", c.to_ascii_uppercase()),
+        '0'..='9' => format!("Digit{}", c),
+        '=' | '+' => "Equal".to_string(),
+        '-' | '_' => "Minus".to_string(),
+        ' ' => "Space".to_string(),
+        _ => c.to_string(),
+    }
+}
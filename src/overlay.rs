@@ -0,0 +1,139 @@
+use ratatui::{
+    layout::{Position, Rect},
+    style::{Color, Style, Stylize},
+    text::Line,
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+    Frame,
+};
+use std::collections::VecDeque;
+
+use crate::hit_test::HitMap;
+use crate::listeners::ListenerCounts;
+use crate::theme::Theme;
+
+const PANEL_WIDTH: u16 = 40;
+
+// Whether the page URL asks for the overlay from the start, `?overlay=on`
+pub fn requested(query: &str) -> bool {
+    query.trim_start_matches('?').split('&').any(|pair| pair == "overlay=on")
+}
+
+// What the app knows about itself that the overlay shows
+#[derive(Debug, Clone)]
+pub struct Stats<'a> {
+    pub screen: String,
+    pub focus: Option<&'a str>,     // Label of the focused control
+    pub listeners: ListenerCounts,
+    pub subscriptions: usize,
+    pub zoom: u16,
+}
+
+// Developer overlay, toggled with Alt+Shift+O: outlines every clickable
+// region over the screen and shows what the app is doing in a corner.
+// Clicks still go to the screen below.
+#[derive(Debug, Default)]
+pub struct Overlay {
+    draws: VecDeque<f64>,   // When each draw of the last second started
+    draw_ms: f64,           // How long the last draw took
+}
+
+impl Overlay {
+    // Returns the time the draw started, for `end_draw`
+    pub fn start_draw(&mut self) -> f64 {
+        let now = now();
+
+        while self.draws.front().is_some_and(|start| now - start > 1000.0) {
+            self.draws.pop_front();
+        }
+        self.draws.push_back(now);
+
+        now
+    }
+
+    pub fn end_draw(&mut self, started: f64) {
+        self.draw_ms = now() - started;
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, stats: &Stats, theme: &Theme, hits: &HitMap) {
+        let hovered = hits.pointer.and_then(|cell| {
+            hits.regions().rev().find(|(region, _)| region.contains(cell))
+        });
+
+        for (region, _) in hits.regions() {
+            let color = if hovered.is_some_and(|(hovered, _)| hovered == region) {
+                theme.success
+            } else {
+                theme.highlight
+            };
+            outline(frame, *region, color);
+        }
+
+        if let Some(cell) = hits.pointer {
+            if frame.area().contains(cell) {
+                frame.buffer_mut()[cell].set_style(Style::default().fg(theme.background).bg(theme.warning));
+            }
+        }
+
+        let pointer = hits.pointer.map_or("-".to_string(), |cell| format!("{}, {}", cell.x, cell.y));
+        let hit = hovered.map_or("-".to_string(), |(_, action)| format!("{:?}", action));
+
+        let lines = [
+            format!("Screen     {}", stats.screen),
+            format!("Focus      {}", stats.focus.unwrap_or("-")),
+            format!("Pointer    {}", pointer),
+            format!("Regions    {}", hits.regions().count()),
+            format!("Hit        {}", hit),
+            format!("Draw       {:.1} ms, {}/s", self.draw_ms, self.draws.len()),
+            format!(
+                "Listeners  app {}, screen {}, off {}",
+                stats.listeners.app, stats.listeners.screen, stats.listeners.detached,
+            ),
+            format!("Ticks      {}", stats.subscriptions),
+            format!(
+                "Grid       {}x{}, text {}%",
+                frame.area().width, frame.area().height, stats.zoom,
+            ),
+        ];
+
+        let width = PANEL_WIDTH.min(area.width);
+        let height = (lines.len() as u16 + 2).min(area.height);
+        let panel = Rect::new(area.right().saturating_sub(width), area.y, width, height);
+
+        let paragraph = Paragraph::new(lines.into_iter().map(Line::from).collect::<Vec<_>>())
+            .style(Style::default().fg(theme.text).bg(theme.background))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(theme.highlight))
+                    .title(" Debug ".bold())
+            );
+
+        frame.render_widget(Clear, panel);
+        frame.render_widget(paragraph, panel);
+    }
+}
+
+// Colors the edge cells of `region`, keeping what is drawn in them
+fn outline(frame: &mut Frame, region: Rect, color: Color) {
+    let region = region.intersection(frame.area());
+    if region.is_empty() {
+        return;
+    }
+
+    let buffer = frame.buffer_mut();
+    for y in region.top()..region.bottom() {
+        for x in region.left()..region.right() {
+            let edge = y == region.top() || y == region.bottom() - 1 || x == region.left() || x == region.right() - 1;
+            if edge {
+                buffer[Position::new(x, y)].set_bg(color);
+            }
+        }
+    }
+}
+
+fn now() -> f64 {
+    web_sys::window()
+        .and_then(|window| window.performance())
+        .map_or(0.0, |performance| performance.now())
+}
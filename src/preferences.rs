@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+
+use crate::buttons::ArrowKeys;
+use crate::demo::demo::DemoProgress;
+use crate::i18n::Language;
+use crate::storage;
+use crate::theme::ThemeName;
+use crate::zoom::Zoom;
+
+// Everything remembered between visits, kept as one record
+const PREFERENCES_KEY: &str = "alertangel.preferences";
+
+// Version of the record's layout, see `migrate`
+const VERSION: u32 = 1;
+
+// The visitor's choices and where they got to in the demo. `None` means
+// no choice was made, and the system or browser setting applies.
+//
+// Stored in `localStorage` one field per line, like the message catalogs:
+//
+//     version = 1
+//     theme = high-contrast
+//     language = de
+//     zoom = 150
+//     demo.path = 0 2
+//     demo.keys = ArrowDown ArrowRight
+//
+// A field that is missing or can't be read falls back to its default on
+// its own, so one bad value never costs the visitor the rest.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Preferences {
+    pub visited: bool,  // The splash has been shown, for `SplashMode::FirstVisit`
+    pub theme: Option<ThemeName>,
+    pub language: Option<Language>,
+    pub zoom: Zoom,
+    pub demo: DemoProgress,
+}
+
+type Fields = HashMap<String, String>;
+
+impl Preferences {
+    // Without storage (disabled by the browser, or a sandboxed frame) this
+    // is the defaults, and choices last until the page is closed
+    pub fn load() -> Self {
+        if !storage::available() {
+            log::info!("No local storage, preferences will not be kept");
+            return Self::default();
+        }
+
+        match storage::get(PREFERENCES_KEY) {
+            Some(record) => Self::from_record(&record),
+            None => Self::default(),
+        }
+    }
+
+    // A record without a version it can read is not trusted with any of
+    // its fields
+    fn from_record(record: &str) -> Self {
+        let (version, fields) = parse(record);
+
+        match version {
+            Some(version) if version > VERSION => {
+                log::warn!("Preferences are from a newer version ({}), starting afresh", version);
+                Self::default()
+            }
+            Some(version) => Self::from_fields(&migrate(version, fields)),
+            None => {
+                log::warn!("Preferences have no readable version, starting afresh");
+                Self::default()
+            }
+        }
+    }
+
+    pub fn save(&self) {
+        if let Err(error) = storage::set(PREFERENCES_KEY, &self.to_record()) {
+            log::warn!("Failed to save preferences: {}", error);
+        }
+    }
+
+    fn from_fields(fields: &Fields) -> Self {
+        let get = |name: &str| fields.get(name).map(String::as_str);
+
+        Self {
+            visited: get("visited") == Some("1"),
+            theme: get("theme").and_then(ThemeName::from_key),
+            language: get("language").and_then(Language::from_tag),
+            zoom: get("zoom")
+                .and_then(|percent| percent.parse().ok())
+                .and_then(Zoom::from_percent)
+                .unwrap_or_default(),
+            demo: DemoProgress {
+                path: get("demo.path")
+                    .map(|path| path.split_whitespace().filter_map(|index| index.parse().ok()).collect())
+                    .unwrap_or_default(),
+                cursor: get("demo.cursor").and_then(|cursor| cursor.parse().ok()).unwrap_or(0),
+                keys: get("demo.keys")
+                    .map(|keys| keys.split_whitespace().filter_map(ArrowKeys::from_key_string).collect())
+                    .unwrap_or_default(),
+            },
+        }
+    }
+
+    fn to_record(&self) -> String {
+        let mut lines = vec![format!("version = {}", VERSION)];
+
+        if self.visited {
+            lines.push("visited = 1".to_string());
+        }
+        if let Some(theme) = self.theme {
+            lines.push(format!("theme = {}", theme.key()));
+        }
+        if let Some(language) = self.language {
+            lines.push(format!("language = {}", language.code()));
+        }
+        lines.push(format!("zoom = {}", self.zoom.percent()));
+
+        let path: Vec<String> = self.demo.path.iter().map(usize::to_string).collect();
+        let keys: Vec<&str> = self.demo.keys.iter().map(ArrowKeys::as_key_string).collect();
+        lines.push(format!("demo.path = {}", path.join(" ")));
+        lines.push(format!("demo.cursor = {}", self.demo.cursor));
+        lines.push(format!("demo.keys = {}", keys.join(" ")));
+
+        lines.join("\n")
+    }
+}
+
+// Brings fields of an older record up to date, one version at a time. A
+// change to how a field is stored bumps `VERSION` and adds a step here,
+// like:
+//
+//     if version < 2 {
+//         fields = ...;
+//     }
+//
+// Version 1 is the first layout, so for now there is nothing to do.
+fn migrate(version: u32, fields: Fields) -> Fields {
+    debug_assert!(version <= VERSION);
+    fields
+}
+
+fn parse(record: &str) -> (Option<u32>, Fields) {
+    let mut fields: Fields = record
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .collect();
+
+    let version = fields.remove("version").and_then(|version| version.parse().ok());
+
+    (version, fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chosen() -> Preferences {
+        Preferences {
+            visited: true,
+            theme: Some(ThemeName::HighContrast),
+            language: Language::from_tag("de"),
+            zoom: Zoom::from_percent(150).unwrap(),
+            demo: DemoProgress {
+                path: vec![0, 2],
+                cursor: 1,
+                keys: vec![ArrowKeys::BottomArrow, ArrowKeys::RightArrow],
+            },
+        }
+    }
+
+    #[test]
+    fn records_read_back_the_same() {
+        let preferences = chosen();
+        let (version, fields) = parse(&preferences.to_record());
+
+        assert_eq!(version, Some(VERSION));
+        assert_eq!(Preferences::from_fields(&fields), preferences);
+        assert_eq!(Preferences::from_record(&Preferences::default().to_record()), Preferences::default());
+    }
+
+    #[test]
+    fn a_bad_field_costs_only_itself() {
+        let record = chosen()
+            .to_record()
+            .replace("zoom = 150", "zoom = 151")
+            .replace("theme = high-contrast", "theme = sepia");
+        let preferences = Preferences::from_record(&record);
+
+        assert_eq!(preferences.zoom, Zoom::default());
+        assert_eq!(preferences.theme, None);
+        assert_eq!(preferences, Preferences { zoom: Zoom::default(), theme: None, ..chosen() });
+    }
+
+    #[test]
+    fn newer_or_unversioned_records_start_afresh() {
+        let record = chosen().to_record();
+
+        let newer = record.replace(&format!("version = {}", VERSION), &format!("version = {}", VERSION + 1));
+        assert_eq!(Preferences::from_record(&newer), Preferences::default());
+
+        let unversioned = record.lines().filter(|line| !line.starts_with("version")).collect::<Vec<_>>().join("\n");
+        assert_eq!(Preferences::from_record(&unversioned), Preferences::default());
+
+        let unreadable = record.replace(&format!("version = {}", VERSION), "version = two");
+        assert_eq!(Preferences::from_record(&unreadable), Preferences::default());
+    }
+}
@@ -0,0 +1,42 @@
+use ratatui::layout::{Margin, Rect};
+
+// Width classes that screens pick their layout by. Layouts are worked out
+// from the frame on every draw and a window resize redraws, so switching
+// between them as the window changes needs nothing else.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Breakpoint {
+    Compact,    // Phones and narrow windows
+    Regular,
+    Wide,       // Room to put things side by side
+}
+
+const REGULAR_WIDTH: u16 = 80;
+const WIDE_WIDTH: u16 = 120;
+
+// Fewer rows than this and screens drop spacing and secondary text
+const SHORT_HEIGHT: u16 = 24;
+
+impl Breakpoint {
+    pub fn of(area: Rect) -> Self {
+        if area.width < REGULAR_WIDTH {
+            Breakpoint::Compact
+        } else if area.width < WIDE_WIDTH {
+            Breakpoint::Regular
+        } else {
+            Breakpoint::Wide
+        }
+    }
+
+    // Space kept clear around centered content
+    pub fn margin(self) -> Margin {
+        match self {
+            Breakpoint::Compact => Margin { horizontal: 1, vertical: 0 },
+            Breakpoint::Regular => Margin { horizontal: 10, vertical: 1 },
+            Breakpoint::Wide => Margin { horizontal: 20, vertical: 2 },
+        }
+    }
+}
+
+pub fn is_short(area: Rect) -> bool {
+    area.height < SHORT_HEIGHT
+}
@@ -0,0 +1,120 @@
+use wasm_bindgen::JsValue;
+
+use crate::Screen;
+use crate::blog::posts;
+use crate::state::HistoryStep;
+
+// Every screen has an address in the URL hash, so it can be bookmarked,
+// shared and reached with the browser's Back and Forward buttons:
+//
+//     #/                  Home (and the splash in front of it)
+//     #/demo
+//     #/blog
+//     #/blog/<slug>
+//     #/contact
+pub fn path(screen: Screen) -> String {
+    match screen {
+        Screen::Startup | Screen::Home => "#/".to_string(),
+        Screen::Demo => "#/demo".to_string(),
+        Screen::Blog => "#/blog".to_string(),
+        Screen::Post(index) => match posts::all().get(index) {
+            Some(post) => format!("#/blog/{}", post.slug),
+            None => "#/blog".to_string(),
+        },
+        Screen::Contact => "#/contact".to_string(),
+    }
+}
+
+// The screen for a hash. Unknown addresses lead home, unknown posts to
+// the post index. Empty segments don't count, so stray slashes are fine.
+pub fn parse(hash: &str) -> Screen {
+    let segments: Vec<&str> = hash.trim_start_matches('#').split('/').filter(|s| !s.is_empty()).collect();
+
+    match segments.as_slice() {
+        ["demo"] => Screen::Demo,
+        ["blog"] => Screen::Blog,
+        ["blog", slug] => posts::all()
+            .iter()
+            .position(|post| post.slug == *slug)
+            .map_or(Screen::Blog, Screen::Post),
+        ["blog", ..] => Screen::Blog,
+        ["contact"] => Screen::Contact,
+        _ => Screen::Home,
+    }
+}
+
+// The screen in the address bar right now
+pub fn current() -> Screen {
+    parse(&hash())
+}
+
+// The screen a visitor asked for by URL, if any. Going straight to the
+// site (or to `#/`) is not a deep link.
+pub fn deep_link() -> Option<Screen> {
+    match current() {
+        Screen::Home => None,
+        screen => Some(screen),
+    }
+}
+
+// Mirrors a navigator step in the browser history. Back and Forward move
+// the browser too, which answers with a `popstate` for a screen the app
+// is already showing.
+pub fn sync(step: HistoryStep) {
+    let Some(history) = web_sys::window().and_then(|window| window.history().ok()) else {
+        return;
+    };
+
+    let result = match step {
+        HistoryStep::Push(screen) => history.push_state_with_url(&JsValue::NULL, "", Some(&path(screen))),
+        HistoryStep::Replace(screen) => history.replace_state_with_url(&JsValue::NULL, "", Some(&path(screen))),
+        HistoryStep::Back => history.back(),
+        HistoryStep::Forward => history.forward(),
+    };
+
+    if result.is_err() {
+        log::warn!("Failed to update browser history for {:?}", step);
+    }
+}
+
+fn hash() -> String {
+    web_sys::window()
+        .and_then(|window| window.location().hash().ok())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_screen_reads_back_from_its_path() {
+        let posts = (0..posts::all().len()).map(Screen::Post);
+        for screen in [Screen::Home, Screen::Demo, Screen::Blog, Screen::Contact].into_iter().chain(posts) {
+            assert_eq!(parse(&path(screen)), screen, "{}", path(screen));
+        }
+
+        // The splash has no address of its own
+        assert_eq!(parse(&path(Screen::Startup)), Screen::Home);
+    }
+
+    #[test]
+    fn stray_slashes() {
+        let slug = posts::all()[0].slug;
+
+        assert_eq!(parse("#/demo/"), Screen::Demo);
+        assert_eq!(parse("#//demo"), Screen::Demo);
+        assert_eq!(parse("#/blog//"), Screen::Blog);
+        assert_eq!(parse(&format!("#/blog//{slug}/")), Screen::Post(0));
+        assert_eq!(parse("#//"), Screen::Home);
+        assert_eq!(parse(""), Screen::Home);
+    }
+
+    #[test]
+    fn unknown_addresses() {
+        assert_eq!(parse("#/blog/no-such-post"), Screen::Blog);
+        assert_eq!(parse("#/blog/a/b"), Screen::Blog);
+        assert_eq!(parse("#/demo/more"), Screen::Home);
+        assert_eq!(parse("#/nowhere"), Screen::Home);
+    }
+}
@@ -0,0 +1,106 @@
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+use crate::MAX_TICK_MS;
+use crate::listeners::Scope;
+use crate::state::Msg;
+
+struct Subscription {
+    scope: Scope,
+    interval_ms: f64,
+    tick: fn(u32) -> Msg,
+    last: Option<f64>,
+}
+
+// Time source for animations and timers, driven by requestAnimationFrame.
+// Screens subscribe with a tick rate and get a message carrying the elapsed
+// milliseconds at roughly that rate; nothing runs while nobody subscribes.
+pub struct Scheduler {
+    subscriptions: Vec<Subscription>,
+    frame: Option<i32>,
+    on_frame: Closure<dyn FnMut(f64)>,
+}
+
+impl Scheduler {
+    // `on_frame` is called with the frame timestamp and is expected to call
+    // `due` and `request_frame` on this scheduler
+    pub fn new<F>(on_frame: F) -> Self
+    where
+        F: FnMut(f64) + 'static,
+    {
+        Self {
+            subscriptions: Vec::new(),
+            frame: None,
+            on_frame: Closure::wrap(Box::new(on_frame) as Box<dyn FnMut(f64)>),
+        }
+    }
+
+    // Subscribing again with the same scope and rate replaces the old tick
+    pub fn subscribe(&mut self, scope: Scope, interval_ms: u32, tick: fn(u32) -> Msg) {
+        let interval_ms = f64::from(interval_ms);
+
+        self.subscriptions.retain(|s| !(s.scope == scope && s.interval_ms == interval_ms));
+        self.subscriptions.push(Subscription {
+            scope,
+            interval_ms,
+            tick,
+            last: None,
+        });
+
+        self.request_frame();
+    }
+
+    pub fn unsubscribe(&mut self, scope: Scope) {
+        self.subscriptions.retain(|s| s.scope != scope);
+
+        if self.subscriptions.is_empty() {
+            self.cancel_frame();
+        }
+    }
+
+    pub fn subscriptions(&self) -> usize {
+        self.subscriptions.len()
+    }
+
+    // Messages for every subscription whose interval has passed by `now`
+    pub fn due(&mut self, now: f64) -> Vec<Msg> {
+        self.frame = None;
+
+        let mut msgs = Vec::new();
+        for subscription in self.subscriptions.iter_mut() {
+            let Some(last) = subscription.last else {
+                subscription.last = Some(now);
+                continue;
+            };
+
+            let elapsed = now - last;
+            if elapsed >= subscription.interval_ms {
+                subscription.last = Some(now);
+                msgs.push((subscription.tick)(elapsed.min(MAX_TICK_MS) as u32));
+            }
+        }
+
+        msgs
+    }
+
+    pub fn request_frame(&mut self) {
+        if self.frame.is_some() || self.subscriptions.is_empty() {
+            return;
+        }
+
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+
+        match window.request_animation_frame(self.on_frame.as_ref().unchecked_ref()) {
+            Ok(handle) => self.frame = Some(handle),
+            Err(_) => log::warn!("Failed to request animation frame"),
+        }
+    }
+
+    fn cancel_frame(&mut self) {
+        if let (Some(handle), Some(window)) = (self.frame.take(), web_sys::window()) {
+            let _ = window.cancel_animation_frame(handle);
+        }
+    }
+}
@@ -1,9 +1,26 @@
 use ratatui::{
-    layout::{Alignment, Constraint, Layout},
-    style::{Color,Style, Stylize},
-    widgets::Paragraph,
-    Terminal,
+    layout::{Alignment, Constraint, Flex, Layout, Rect},
+    style::{Style, Stylize},
+    widgets::{Gauge, Paragraph},
+    Frame,
 };
+#[cfg(not(feature = "native"))]
+use {
+    std::io,
+    crate::accessibility::{Control, Description},
+    crate::app,
+    crate::listeners::{self, Listeners, Scope},
+    crate::scheduler::Scheduler,
+};
+
+use crate::Screen;
+use crate::i18n::t;
+use crate::keys::KeyPress;
+use crate::responsive::{self, Breakpoint};
+use crate::state::{Msg, Update};
+use crate::theme::Theme;
+
+const TICK_MS: u32 = 50;
 
 const LOGO: &str = r#"
  █████╗ ██╗     ███████╗██████╗ ████████╗
@@ -20,26 +37,256 @@ const LOGO: &str = r#"
 ╚═╝  ╚═╝╚═╝  ╚═══╝ ╚═════╝ ╚══════╝╚══════╝
 "#;
 
+// For phones and short windows, where `LOGO` does not fit
+const COMPACT_LOGO: &str = r#"
+╔═╗╦  ╔═╗╦═╗╔╦╗
+╠═╣║  ║╣ ╠╦╝ ║ 
+╩ ╩╩═╝╚═╝╩╚═ ╩ 
+╔═╗╔╗╔╔═╗╔═╗╦  
+╠═╣║║║║ ╦║╣ ║  
+╩ ╩╝╚╝╚═╝╚═╝╩═╝
+"#;
+
+// Boot messages, in the order the device prints them, with the share of
+// the splash after which each one appears
+const BOOT_STEPS: &[(f64, &str)] = &[
+    (0.0, "startup.powering_on"),
+    (0.2, "startup.checking_sensors"),
+    (0.45, "startup.connecting"),
+    (0.7, "startup.syncing_contacts"),
+    (0.9, "startup.ready"),
+];
+
+// Share of the splash spent revealing the logo, line by line
+const REVEAL_SHARE: f64 = 0.4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplashMode {
+    Always,
+    FirstVisit,
+    Never,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SplashConfig {
+    pub mode: SplashMode,
+    pub duration_ms: u32,
+}
+
+impl Default for SplashConfig {
+    fn default() -> Self {
+        Self {
+            mode: SplashMode::FirstVisit,
+            duration_ms: 3000,
+        }
+    }
+}
+
+impl SplashConfig {
+    // Overrides from the page URL, e.g. `?splash=always&splash_ms=5000`
+    pub fn from_query(query: &str) -> Self {
+        let mut config = Self::default();
+
+        for pair in query.trim_start_matches('?').split('&') {
+            match pair.split_once('=') {
+                Some(("splash", "always")) => config.mode = SplashMode::Always,
+                Some(("splash", "first-visit")) => config.mode = SplashMode::FirstVisit,
+                Some(("splash", "never")) => config.mode = SplashMode::Never,
+                Some(("splash_ms", ms)) => {
+                    if let Ok(ms) = ms.parse() {
+                        config.duration_ms = ms;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        config
+    }
+
+    pub fn should_show(&self, visited: bool) -> bool {
+        match self.mode {
+            SplashMode::Always => true,
+            SplashMode::FirstVisit => !visited,
+            SplashMode::Never => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StartupState {
+    pub duration_ms: u32,
+    pub elapsed_ms: u32,
+}
+
+impl Default for StartupState {
+    fn default() -> Self {
+        Self::new(SplashConfig::default().duration_ms)
+    }
+}
+
+impl StartupState {
+    pub fn new(duration_ms: u32) -> Self {
+        Self {
+            duration_ms,
+            elapsed_ms: 0,
+        }
+    }
+
+    // How far through the boot sequence we are, from 0 to 1
+    pub fn progress(&self) -> f64 {
+        if self.duration_ms == 0 {
+            return 1.0;
+        }
+
+        (f64::from(self.elapsed_ms) / f64::from(self.duration_ms)).min(1.0)
+    }
+
+    // How many of a logo's `total` lines are showing
+    pub fn logo_lines(&self, total: usize) -> usize {
+        let revealed = (self.progress() / REVEAL_SHARE).min(1.0);
+
+        (revealed * total as f64).ceil() as usize
+    }
+
+    pub fn status(&self) -> &'static str {
+        let progress = self.progress();
+
+        BOOT_STEPS
+            .iter()
+            .rev()
+            .find(|(at, _)| progress >= *at)
+            .map_or("", |(_, status)| t(status))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StartupMsg {
+    Tick(u32),
+    Skip,
+}
+
 pub struct StartupScreen;
 
 impl  StartupScreen {
-    pub fn start<B>(terminal: &mut Terminal<B>)
-    where
-        B: ratatui::backend::Backend,
-    {
-        terminal.draw(|frame| {
-            let layout = Layout::vertical([
-                Constraint::Percentage(40),
-                Constraint::Min(30),
-                Constraint::Percentage(40),
-            ])
-                .split(frame.area());
-
-            let logo_widget = Paragraph::new(LOGO)
-                .style(Style::default().fg(Color::LightYellow).bold())
-                .alignment(Alignment::Center);
-
-            frame.render_widget(logo_widget, layout[1]);
-        }).unwrap();
+    pub fn update(state: &mut StartupState, msg: StartupMsg) -> Update {
+        match msg {
+            StartupMsg::Tick(elapsed_ms) => {
+                state.elapsed_ms += elapsed_ms;
+
+                if state.elapsed_ms >= state.duration_ms {
+                    Self::finish()
+                } else {
+                    Update::Redraw
+                }
+            }
+            StartupMsg::Skip => Self::finish(),
+        }
+    }
+
+    // Replace rather than navigate, so "back" never returns to the splash
+    fn finish() -> Update {
+        Update::Send(Msg::Replace(Screen::Home))
+    }
+
+    // How often the screen wants a tick, and the message for it
+    pub fn ticks() -> (u32, fn(u32) -> Msg) {
+        (TICK_MS, |elapsed_ms| Msg::Startup(StartupMsg::Tick(elapsed_ms)))
+    }
+
+    // Any key skips, except browser and system shortcuts
+    pub fn key(press: &KeyPress) -> Option<Msg> {
+        (!press.is_shortcut()).then_some(Msg::Startup(StartupMsg::Skip))
+    }
+
+    pub fn render(frame: &mut Frame, area: Rect, state: &StartupState, theme: &Theme) {
+        let logo = if Breakpoint::of(area) == Breakpoint::Compact || responsive::is_short(area) {
+            COMPACT_LOGO
+        } else {
+            LOGO
+        };
+        let logo: Vec<&str> = logo.trim_matches('\n').lines().collect();
+        let logo_width = logo.iter().map(|line| line.chars().count()).max().unwrap_or(0);
+
+        let layout = Layout::vertical([
+            Constraint::Length(logo.len() as u16),  // Logo
+            Constraint::Length(2),                  // Spacing
+            Constraint::Length(1),                  // Progress bar
+            Constraint::Length(1),                  // Boot status
+            Constraint::Length(1),                  // Spacing
+            Constraint::Length(1),                  // Skip hint
+        ])
+            .flex(Flex::Center)
+            .split(area);
+
+        // Lines not revealed yet are left blank so the logo doesn't move
+        let revealed = state.logo_lines(logo.len());
+        let logo_text: Vec<&str> = logo
+            .iter()
+            .enumerate()
+            .map(|(i, line)| if i < revealed { *line } else { "" })
+            .collect();
+
+        let logo_widget = Paragraph::new(logo_text.join("\n"))
+            .style(Style::default().fg(theme.title).bold())
+            .alignment(Alignment::Center);
+
+        frame.render_widget(logo_widget, layout[0]);
+
+        let bar_area = Layout::horizontal([Constraint::Length(logo_width as u16)])
+            .flex(Flex::Center)
+            .split(layout[2])[0];
+
+        let progress = Gauge::default()
+            .gauge_style(Style::default().fg(theme.title).bg(theme.faint))
+            .ratio(state.progress())
+            .label("");
+
+        frame.render_widget(progress, bar_area);
+
+        let status = Paragraph::new(state.status())
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+
+        frame.render_widget(status, layout[3]);
+
+        let hint = Paragraph::new(t("startup.skip_hint"))
+            .style(Style::default().fg(theme.faint).italic())
+            .alignment(Alignment::Center);
+
+        frame.render_widget(hint, layout[5]);
+    }
+}
+
+#[cfg(not(feature = "native"))]
+impl StartupScreen {
+    pub fn subscribe(scheduler: &mut Scheduler) {
+        let (interval_ms, tick) = Self::ticks();
+        scheduler.subscribe(Scope::Screen(Screen::Startup), interval_ms, tick);
+    }
+
+    pub fn listen(listeners: &mut Listeners) -> io::Result<()> {
+        let document = listeners::document()?;
+        let scope = Scope::Screen(Screen::Startup);
+
+        listeners.add(scope, &document, "keydown", |event: web_sys::KeyboardEvent| {
+            if let Some(msg) = Self::key(&KeyPress::from_event(&event)) {
+                event.prevent_default();
+                app::dispatch(msg);
+            }
+        });
+
+        listeners.add(scope, &document, "click", |_: web_sys::MouseEvent| {
+            app::dispatch(Msg::Startup(StartupMsg::Skip));
+        });
+
+        Ok(())
+    }
+
+    pub fn describe(state: &StartupState) -> Description {
+        Description::new("AlertAngel")
+            .text(t("startup.starting"))
+            .control(Control::button(t("startup.skip"), Msg::Startup(StartupMsg::Skip)))
+            .status(state.status())
     }
 }
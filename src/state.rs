@@ -0,0 +1,524 @@
+use ratatui::layout::Size;
+
+use crate::Screen;
+use crate::blog::blog::{BlogMsg, BlogScreen, BlogState, PostMsg, PostScreen, PostState};
+use crate::blog::posts;
+use crate::startup::{StartupMsg, StartupScreen, StartupState};
+use crate::home::{HomeMsg, HomeScreen, HomeState};
+use crate::demo::alert::Alert;
+use crate::demo::demo::{DemoMsg, DemoScreen, DemoState};
+use crate::contact::{ContactMsg, ContactScreen, ContactState};
+use crate::contact::Focus;
+use crate::crash::{CrashMsg, CrashScreen, CrashState};
+use crate::i18n::Language;
+use crate::preferences::Preferences;
+use crate::theme::ThemeName;
+use crate::zoom::Zoom;
+
+// Everything that can change the app. DOM handlers and timers never touch
+// state or draw themselves, they dispatch one of these instead.
+//
+// `Route` and `SystemTheme` come from the page's URL and media queries,
+// which the terminal build doesn't have.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "native", allow(dead_code))]
+pub enum Msg {
+    Navigate(Screen),   // Go to a screen, recording the current one in history
+    Replace(Screen),    // Go to a screen without recording history
+    Back,
+    Forward,
+    Route(Screen),      // The browser moved to a screen's URL
+    Resize,
+    CycleTheme,             // The visitor picked the next theme
+    SystemTheme(ThemeName), // The system's color preferences changed
+    CycleLanguage,          // The visitor picked the next language
+    ZoomIn,                 // Larger text
+    ZoomOut,                // Smaller text
+    ZoomReset,              // Text back to its normal size
+    ResetPreferences,       // Forget every choice, back to the system's and browser's settings
+    Startup(StartupMsg),
+    Home(HomeMsg),
+    Demo(DemoMsg),
+    Blog(BlogMsg),
+    Post(PostMsg),
+    Contact(ContactMsg),
+    Crash(CrashMsg),
+}
+
+// What a screen's update function asks of the store
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Update {
+    Ignored,            // Nothing changed, no redraw needed
+    Redraw,             // Screen state changed
+    Send(Msg),          // Hand a follow-up message back to the store
+    Perform(Effect),    // Screen state changed, and the page has something to do
+}
+
+// Something only the page can do. Updates never touch the browser, they
+// ask for one of these, and the app carries it out once the update is done.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Effect {
+    Open(String),                               // Follow a link, like a `mailto:` one
+    Download { name: &'static str, url: String },   // Save what `url` points to as a file
+    SavePreferences,                            // Keep `AppState::preferences` for the next visit
+    Reload,                                     // Start the page over
+    Copy(String),                               // To the clipboard, answered with `CrashMsg::Copied`
+}
+
+// A change to the navigator that the browser history has to follow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryStep {
+    Push(Screen),
+    Replace(Screen),
+    Back,
+    Forward,
+}
+
+// Browser-style navigation history
+#[derive(Debug, Clone)]
+pub struct Navigator {
+    current: Screen,
+    back: Vec<Screen>,
+    forward: Vec<Screen>,
+    steps: Vec<HistoryStep>,
+}
+
+impl Navigator {
+    pub fn new(start: Screen) -> Self {
+        Self {
+            current: start,
+            back: Vec::new(),
+            forward: Vec::new(),
+            steps: Vec::new(),
+        }
+    }
+
+    pub fn current(&self) -> Screen {
+        self.current
+    }
+
+    pub fn push(&mut self, screen: Screen) -> bool {
+        if screen == self.current {
+            return false;
+        }
+
+        self.back.push(self.current);
+        self.forward.clear();
+        self.current = screen;
+        self.steps.push(HistoryStep::Push(screen));
+        true
+    }
+
+    pub fn replace(&mut self, screen: Screen) -> bool {
+        if screen == self.current {
+            return false;
+        }
+
+        self.current = screen;
+        self.steps.push(HistoryStep::Replace(screen));
+        true
+    }
+
+    pub fn back(&mut self) -> bool {
+        match self.back.pop() {
+            Some(previous) => {
+                self.forward.push(self.current);
+                self.current = previous;
+                self.steps.push(HistoryStep::Back);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn forward(&mut self) -> bool {
+        match self.forward.pop() {
+            Some(next) => {
+                self.back.push(self.current);
+                self.current = next;
+                self.steps.push(HistoryStep::Forward);
+                true
+            }
+            None => false,
+        }
+    }
+
+    // The browser already moved (its Back or Forward button, or an edited
+    // URL), so this follows along without recording steps for it
+    pub fn visit(&mut self, screen: Screen) -> bool {
+        let steps = self.steps.len();
+
+        let moved = if self.back.last() == Some(&screen) {
+            self.back()
+        } else if self.forward.last() == Some(&screen) {
+            self.forward()
+        } else {
+            self.push(screen)
+        };
+
+        self.steps.truncate(steps);
+        moved
+    }
+
+    // Steps taken since the last call, oldest first
+    pub fn take_steps(&mut self) -> Vec<HistoryStep> {
+        std::mem::take(&mut self.steps)
+    }
+}
+
+// The whole app state. Each screen owns one sub-state, which is reset
+// when that screen is left.
+#[derive(Debug, Clone)]
+pub struct AppState {
+    pub nav: Navigator,
+    pub size: Size,     // Size of the area screens draw in, kept up to date by the app
+    pub theme: ThemeName,
+    pub language: Language,
+    pub zoom: Zoom,
+    pub preferences: Preferences,   // What is remembered for the next visit
+    pub startup: StartupState,
+    pub home: HomeState,
+    pub demo: DemoState,
+    pub blog: BlogState,
+    pub post: PostState,
+    pub contact: ContactState,
+    pub crash: Option<CrashState>,  // Something failed, and the error screen shows instead
+    effects: Vec<Effect>,
+}
+
+impl AppState {
+    pub fn new(start: Screen) -> Self {
+        Self {
+            nav: Navigator::new(start),
+            size: Size::default(),
+            theme: ThemeName::default(),
+            language: Language::default(),
+            zoom: Zoom::default(),
+            preferences: Preferences::default(),
+            startup: StartupState::default(),
+            home: HomeState::default(),
+            demo: DemoState::default(),
+            blog: BlogState::default(),
+            post: PostState::default(),
+            contact: ContactState::default(),
+            crash: None,
+            effects: Vec::new(),
+        }
+    }
+
+    pub fn screen(&self) -> Screen {
+        self.nav.current()
+    }
+
+    // Whether a text field has the keyboard
+    pub fn is_typing(&self) -> bool {
+        self.crash.is_none() && self.screen() == Screen::Contact && matches!(self.contact.focus, Focus::Field(_))
+    }
+
+    // Picks up where the last visit left off
+    pub fn restore(&mut self, preferences: Preferences) {
+        self.theme = preferences.theme.unwrap_or_else(ThemeName::system);
+        self.language = preferences.language.or_else(Language::detect).unwrap_or_default();
+        self.zoom = preferences.zoom;
+        self.demo.restore(&preferences.demo);
+        self.preferences = preferences;
+    }
+
+    // Returns whether anything changed and the screen needs redrawing
+    pub fn update(&mut self, msg: Msg) -> bool {
+        let from = self.screen();
+
+        // After a failure the error screen is all there is, though it
+        // still follows the window and the page-wide settings
+        if self.crash.is_some() && !Self::allowed_after_crash(&msg) {
+            return false;
+        }
+
+        let update = match msg {
+            Msg::Navigate(screen) => Self::redraw_if(self.nav.push(screen)),
+            Msg::Replace(screen) => Self::redraw_if(self.nav.replace(screen)),
+            // Deep links start without history, so Back goes up to Home
+            Msg::Back => Self::redraw_if(self.nav.back() || self.nav.push(Screen::Home)),
+            Msg::Forward => Self::redraw_if(self.nav.forward()),
+            Msg::Route(screen) => Self::redraw_if(self.nav.visit(screen)),
+            Msg::Resize => Update::Redraw,
+            Msg::CycleTheme => {
+                self.theme = self.theme.next();
+                self.preferences.theme = Some(self.theme);
+                self.remember();
+                Update::Redraw
+            }
+            // A theme the visitor picked wins over the system's
+            Msg::SystemTheme(theme) if self.preferences.theme.is_none() => {
+                let changed = theme != self.theme;
+                self.theme = theme;
+                Self::redraw_if(changed)
+            }
+            Msg::SystemTheme(_) => Update::Ignored,
+            Msg::CycleLanguage => {
+                self.language = self.language.next();
+                self.preferences.language = Some(self.language);
+                self.remember();
+                Update::Redraw
+            }
+            Msg::ZoomIn => self.zoom_to(self.zoom.larger()),
+            Msg::ZoomOut => self.zoom_to(self.zoom.smaller()),
+            Msg::ZoomReset => self.zoom_to(Zoom::default()),
+            // The splash is not shown again, that is not a setting
+            Msg::ResetPreferences => {
+                self.restore(Preferences {
+                    visited: self.preferences.visited,
+                    ..Preferences::default()
+                });
+                self.remember();
+                Update::Redraw
+            }
+
+            // Messages for a screen that is not showing are stale
+            Msg::Startup(msg) if from == Screen::Startup => StartupScreen::update(&mut self.startup, msg),
+            Msg::Home(msg) if from == Screen::Home => HomeScreen::update(&mut self.home, msg),
+            Msg::Demo(msg) if from == Screen::Demo => {
+                // Ticks only move an alert along, and alerts are not kept
+                let moved = !matches!(msg, DemoMsg::Tick(_));
+                let update = DemoScreen::update(&mut self.demo, msg);
+
+                if moved && self.demo.progress() != self.preferences.demo {
+                    self.preferences.demo = self.demo.progress();
+                    self.remember();
+                }
+
+                update
+            }
+            Msg::Blog(msg) if from == Screen::Blog => BlogScreen::update(&mut self.blog, msg),
+            Msg::Post(msg) => match from {
+                Screen::Post(index) => PostScreen::update(&mut self.post, posts::all().get(index), self.size, msg),
+                _ => Update::Ignored,
+            },
+            Msg::Contact(msg) if from == Screen::Contact => ContactScreen::update(&mut self.contact, msg),
+            Msg::Startup(_) | Msg::Home(_) | Msg::Demo(_) | Msg::Blog(_) | Msg::Contact(_) => Update::Ignored,
+            Msg::Crash(msg) => match &mut self.crash {
+                Some(crash) => CrashScreen::update(crash, msg),
+                None => Update::Ignored,
+            },
+        };
+
+        if self.screen() != from {
+            self.reset(from);
+        }
+
+        match update {
+            Update::Ignored => false,
+            Update::Redraw => true,
+            Update::Send(next) => self.update(next),
+            Update::Perform(effect) => {
+                self.effects.push(effect);
+                true
+            }
+        }
+    }
+
+    // The preferences changed. Saved once the update is done, and once
+    // however many changes it made.
+    fn remember(&mut self) {
+        if !self.effects.contains(&Effect::SavePreferences) {
+            self.effects.push(Effect::SavePreferences);
+        }
+    }
+
+    // Effects asked for since the last call, oldest first
+    pub fn take_effects(&mut self) -> Vec<Effect> {
+        std::mem::take(&mut self.effects)
+    }
+
+    pub fn reset(&mut self, screen: Screen) {
+        match screen {
+            Screen::Startup => self.startup = StartupState::new(self.startup.duration_ms),
+            Screen::Home => self.home = HomeState::default(),
+            // The place in the menus is kept, like on the device, but an
+            // alert ends when the demo is left
+            Screen::Demo => self.demo.alert = Alert::Idle,
+
+            // Keep the place in the index for when a post is closed
+            Screen::Blog => {}
+            Screen::Post(_) => self.post = PostState::default(),
+
+            // A half-written message survives a look at another screen
+            Screen::Contact => self.contact.status = None,
+        }
+    }
+
+    fn allowed_after_crash(msg: &Msg) -> bool {
+        matches!(
+            msg,
+            Msg::Crash(_)
+                | Msg::Resize
+                | Msg::CycleTheme
+                | Msg::SystemTheme(_)
+                | Msg::CycleLanguage
+                | Msg::ZoomIn
+                | Msg::ZoomOut
+                | Msg::ZoomReset
+        )
+    }
+
+    fn zoom_to(&mut self, zoom: Zoom) -> Update {
+        if zoom == self.zoom {
+            return Update::Ignored;
+        }
+
+        self.zoom = zoom;
+        self.preferences.zoom = zoom;
+        self.remember();
+        Update::Redraw
+    }
+
+    fn redraw_if(changed: bool) -> Update {
+        if changed {
+            Update::Redraw
+        } else {
+            Update::Ignored
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buttons::ArrowKeys;
+    use crate::contact::{Delivery, Field, Focus};
+    use crate::demo::demo::DemoMsg;
+    use crate::home::{HomeButton, HomeMsg};
+    use crate::text_input::Edit;
+
+    #[test]
+    fn push_and_replace() {
+        let mut nav = Navigator::new(Screen::Home);
+        assert!(!nav.push(Screen::Home));
+        assert!(nav.push(Screen::Demo));
+        assert!(nav.replace(Screen::Blog));
+        assert!(!nav.replace(Screen::Blog));
+
+        assert_eq!(nav.take_steps(), [HistoryStep::Push(Screen::Demo), HistoryStep::Replace(Screen::Blog)]);
+        assert_eq!(nav.take_steps(), []);
+
+        // The replaced screen is gone from history
+        assert!(nav.back());
+        assert_eq!(nav.current(), Screen::Home);
+        assert!(!nav.back());
+    }
+
+    #[test]
+    fn back_and_forward() {
+        let mut nav = Navigator::new(Screen::Home);
+        nav.push(Screen::Demo);
+        nav.push(Screen::Blog);
+
+        assert!(nav.back());
+        assert!(nav.back());
+        assert_eq!(nav.current(), Screen::Home);
+        assert!(nav.forward());
+        assert_eq!(nav.current(), Screen::Demo);
+
+        // Going somewhere new drops what was ahead
+        nav.push(Screen::Contact);
+        assert!(!nav.forward());
+
+        let steps = nav.take_steps();
+        assert_eq!(steps[2..], [HistoryStep::Back, HistoryStep::Back, HistoryStep::Forward, HistoryStep::Push(Screen::Contact)]);
+    }
+
+    #[test]
+    fn visit_follows_the_browser_without_steps() {
+        let mut nav = Navigator::new(Screen::Home);
+        nav.push(Screen::Demo);
+        nav.take_steps();
+
+        assert!(nav.visit(Screen::Home));
+        assert!(nav.visit(Screen::Demo));
+        assert!(nav.visit(Screen::Contact));
+        assert!(!nav.visit(Screen::Contact));
+        assert_eq!(nav.take_steps(), []);
+
+        // Its Back button went to Demo, the same as ours would
+        assert!(nav.back());
+        assert_eq!(nav.current(), Screen::Demo);
+    }
+
+    #[test]
+    fn back_from_a_deep_link_goes_home() {
+        let mut state = AppState::new(Screen::Contact);
+        assert!(state.update(Msg::Back));
+        assert_eq!(state.screen(), Screen::Home);
+        assert_eq!(state.nav.take_steps(), [HistoryStep::Push(Screen::Home)]);
+
+        let mut home = AppState::new(Screen::Home);
+        assert!(!home.update(Msg::Back));
+    }
+
+    #[test]
+    fn preferences_are_saved_when_they_change() {
+        let mut state = AppState::new(Screen::Home);
+
+        state.update(Msg::Resize);
+        assert_eq!(state.take_effects(), []);
+
+        state.update(Msg::CycleTheme);
+        state.update(Msg::ZoomIn);
+        assert_eq!(state.take_effects(), [Effect::SavePreferences]);
+        assert_eq!(state.preferences.theme, Some(state.theme));
+        assert_eq!(state.preferences.zoom, state.zoom);
+
+        // Ticks leave the demo's place alone, keys move it
+        state.update(Msg::Navigate(Screen::Demo));
+        state.update(Msg::Demo(DemoMsg::Tick(50)));
+        assert_eq!(state.take_effects(), []);
+
+        state.update(Msg::Demo(DemoMsg::Key(ArrowKeys::BottomArrow)));
+        assert_eq!(state.take_effects(), [Effect::SavePreferences]);
+        assert_eq!(state.preferences.demo, state.demo.progress());
+    }
+
+    #[test]
+    fn update_results() {
+        let mut state = AppState::new(Screen::Home);
+
+        // Ignored: a message for a screen that is not showing
+        assert!(!state.update(Msg::Demo(DemoMsg::SimulateFall)));
+
+        // Redraw
+        assert!(state.update(Msg::Home(HomeMsg::FocusNext)));
+        assert_eq!(state.home.focus, Some(HomeButton::Demo));
+
+        // Send: the home screen hands on a navigation
+        assert!(state.update(Msg::Home(HomeMsg::Activate(HomeButton::Demo))));
+        assert_eq!(state.screen(), Screen::Demo);
+        assert_eq!(state.home, HomeState::default());
+    }
+
+    #[test]
+    fn focus_follows_the_screen_reader_mirror() {
+        use crate::blog::blog::BlogMsg;
+
+        let mut state = AppState::new(Screen::Home);
+        assert!(state.update(Msg::Home(HomeMsg::Focus(HomeButton::Demo))));
+        assert_eq!(state.home.focus, Some(HomeButton::Demo));
+
+        state.update(Msg::Navigate(Screen::Blog));
+        assert!(state.update(Msg::Blog(BlogMsg::Select(usize::MAX))));
+        assert_eq!(state.blog.selected, crate::blog::posts::all().len() - 1);
+    }
+
+    #[test]
+    fn effects_wait_for_the_app() {
+        let mut state = AppState::new(Screen::Contact);
+        for (field, value) in Field::ALL.into_iter().zip(["Ann", "ann@example.com", "Hi", "Hello"]) {
+            state.update(Msg::Contact(ContactMsg::Focus(Focus::Field(field))));
+            state.update(Msg::Contact(ContactMsg::Edit(Edit::Insert(value.to_string()))));
+        }
+        assert_eq!(state.take_effects(), []);
+
+        assert!(state.update(Msg::Contact(ContactMsg::Submit(Delivery::Email))));
+        assert!(matches!(state.take_effects()[..], [Effect::Open(ref url)] if url.starts_with("mailto:")));
+        assert_eq!(state.take_effects(), []);
+    }
+}
@@ -0,0 +1,49 @@
+// The one place anything is kept between visits: the browser's local
+// storage. It can be missing (disabled by the browser, or a sandboxed
+// frame), and writes fail when it is full or blocked, which only costs the
+// memory of what was written.
+//
+// The terminal build keeps values in memory for as long as it runs.
+
+#[cfg(not(feature = "native"))]
+pub fn available() -> bool {
+    local().is_some()
+}
+
+#[cfg(not(feature = "native"))]
+pub fn get(key: &str) -> Option<String> {
+    local()?.get_item(key).ok().flatten()
+}
+
+#[cfg(not(feature = "native"))]
+pub fn set(key: &str, value: &str) -> Result<(), String> {
+    let storage = local().ok_or("no local storage")?;
+    storage.set_item(key, value).map_err(|error| format!("{:?}", error))
+}
+
+#[cfg(not(feature = "native"))]
+fn local() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok().flatten()
+}
+
+#[cfg(feature = "native")]
+thread_local! {
+    static MEMORY: std::cell::RefCell<std::collections::HashMap<String, String>> =
+        std::cell::RefCell::new(std::collections::HashMap::new());
+}
+
+#[cfg(feature = "native")]
+pub fn available() -> bool {
+    true
+}
+
+#[cfg(feature = "native")]
+pub fn get(key: &str) -> Option<String> {
+    MEMORY.with(|memory| memory.borrow().get(key).cloned())
+}
+
+#[cfg(feature = "native")]
+pub fn set(key: &str, value: &str) -> Result<(), String> {
+    MEMORY.with(|memory| memory.borrow_mut().insert(key.to_string(), value.to_string()));
+    Ok(())
+}
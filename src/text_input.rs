@@ -0,0 +1,606 @@
+use std::ops::Range;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style, Stylize},
+    widgets::{Block, Widget},
+};
+use unicode_width::UnicodeWidthChar;
+
+use crate::keys::KeyPress;
+use crate::theme::Theme;
+
+// Characters on one screen row, each with its position in the value
+type Row = Vec<(usize, char)>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Motion {
+    Left,
+    Right,
+    WordLeft,
+    WordRight,
+    Home,       // Start of the line
+    End,        // End of the line
+    Up,         // Multi-line only
+    Down,       // Multi-line only
+}
+
+// One change to a `TextInput`. Screens wrap these in their own messages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Edit {
+    Insert(String),         // Typed or pasted, replaces the selection
+    DeleteBackward,
+    DeleteForward,
+    DeleteWordBackward,
+    Move(Motion, bool),     // `true` extends the selection
+    SelectAll,
+}
+
+impl Edit {
+    // The usual text field keys. Enter comes out as a newline, screens
+    // decide what that means for single-line inputs. Other shortcuts are
+    // left to the browser so copy and paste keep working.
+    //
+    // Words are Control on most systems and Option on a Mac, where Command
+    // with an arrow goes to the start or end of the line instead.
+    pub fn from_key(press: &KeyPress) -> Option<Self> {
+        let key = press.key.as_str();
+        let select = press.shift;
+        let word = press.ctrl || press.alt;
+
+        if press.meta {
+            match key {
+                "ArrowLeft" => return Some(Edit::Move(Motion::Home, select)),
+                "ArrowRight" => return Some(Edit::Move(Motion::End, select)),
+                _ => {}
+            }
+        }
+
+        if press.ctrl {
+            match key {
+                "a" | "A" => return Some(Edit::SelectAll),
+                "ArrowLeft" | "ArrowRight" | "Backspace" => {}
+                _ => return None,
+            }
+        }
+
+        let edit = match key {
+            "ArrowLeft" if word => Edit::Move(Motion::WordLeft, select),
+            "ArrowRight" if word => Edit::Move(Motion::WordRight, select),
+            "ArrowLeft" => Edit::Move(Motion::Left, select),
+            "ArrowRight" => Edit::Move(Motion::Right, select),
+            "ArrowUp" => Edit::Move(Motion::Up, select),
+            "ArrowDown" => Edit::Move(Motion::Down, select),
+            "Home" => Edit::Move(Motion::Home, select),
+            "End" => Edit::Move(Motion::End, select),
+            "Backspace" if word => Edit::DeleteWordBackward,
+            "Backspace" => Edit::DeleteBackward,
+            "Delete" => Edit::DeleteForward,
+            "Enter" => Edit::Insert("\n".to_string()),
+
+            // Printable keys come through as a single character
+            _ => {
+                let mut chars = key.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Edit::Insert(c.to_string()),
+                    _ => return None,
+                }
+            }
+        };
+
+        Some(edit)
+    }
+}
+
+// Editable text with a cursor and an optional selection. Positions count
+// characters, not bytes. Kept in screen state and drawn with `TextField`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextInput {
+    value: String,
+    cursor: usize,
+    // Other end of the selection, the cursor being one end
+    anchor: Option<usize>,
+    max_len: Option<usize>,
+    multiline: bool,
+}
+
+impl TextInput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Longest value accepted, in characters. Longer input is cut off.
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.max_len = Some(max_len);
+        self
+    }
+
+    pub fn multiline(mut self, multiline: bool) -> Self {
+        self.multiline = multiline;
+        self
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn is_multiline(&self) -> bool {
+        self.multiline
+    }
+
+    pub fn selection(&self) -> Option<Range<usize>> {
+        let anchor = self.anchor?;
+        Some(anchor.min(self.cursor)..anchor.max(self.cursor))
+    }
+
+    // Returns whether the value, cursor or selection changed
+    pub fn apply(&mut self, edit: Edit) -> bool {
+        let before = (self.value.len(), self.cursor, self.anchor);
+        let mut edited = false;
+
+        match edit {
+            Edit::Insert(text) => edited = self.insert(&text),
+            Edit::DeleteBackward => {
+                if !self.delete_selection() && self.cursor > 0 {
+                    self.remove(self.cursor - 1..self.cursor);
+                }
+            }
+            Edit::DeleteForward => {
+                if !self.delete_selection() && self.cursor < self.len() {
+                    self.remove(self.cursor..self.cursor + 1);
+                }
+            }
+            Edit::DeleteWordBackward => {
+                if !self.delete_selection() {
+                    self.remove(self.word_left()..self.cursor);
+                }
+            }
+            Edit::Move(motion, select) => self.move_cursor(motion, select),
+            Edit::SelectAll => {
+                self.anchor = Some(0);
+                self.cursor = self.len();
+            }
+        }
+
+        if self.anchor == Some(self.cursor) {
+            self.anchor = None;
+        }
+
+        edited || before != (self.value.len(), self.cursor, self.anchor)
+    }
+
+    fn len(&self) -> usize {
+        self.value.chars().count()
+    }
+
+    fn chars(&self) -> Vec<char> {
+        self.value.chars().collect()
+    }
+
+    fn byte(&self, index: usize) -> usize {
+        self.value
+            .char_indices()
+            .nth(index)
+            .map_or(self.value.len(), |(byte, _)| byte)
+    }
+
+    fn remove(&mut self, range: Range<usize>) {
+        let bytes = self.byte(range.start)..self.byte(range.end);
+        self.value.replace_range(bytes, "");
+        self.cursor = range.start;
+        self.anchor = None;
+    }
+
+    fn delete_selection(&mut self) -> bool {
+        match self.selection() {
+            Some(selection) => {
+                self.remove(selection);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn insert(&mut self, text: &str) -> bool {
+        // Pasted line breaks become spaces in a single-line input
+        let text = text.replace("\r\n", "\n");
+        let text: String = text
+            .chars()
+            .map(|c| if c == '\n' && !self.multiline { ' ' } else { c })
+            .filter(|c| *c == '\n' || !c.is_control())
+            .collect();
+
+        let deleted = self.delete_selection();
+
+        let room = self.max_len.map_or(usize::MAX, |max_len| max_len.saturating_sub(self.len()));
+        let text: String = text.chars().take(room).collect();
+        if text.is_empty() {
+            return deleted;
+        }
+
+        let at = self.byte(self.cursor);
+        self.value.insert_str(at, &text);
+        self.cursor += text.chars().count();
+        true
+    }
+
+    fn move_cursor(&mut self, motion: Motion, select: bool) {
+        let selection = self.selection().filter(|_| !select);
+
+        let target = match motion {
+            // Without shift, Left and Right first collapse the selection
+            Motion::Left => selection.map_or(self.cursor.saturating_sub(1), |s| s.start),
+            Motion::Right => selection.map_or((self.cursor + 1).min(self.len()), |s| s.end),
+            Motion::WordLeft => self.word_left(),
+            Motion::WordRight => self.word_right(),
+            Motion::Home => self.line_start(self.cursor),
+            Motion::End => self.line_end(self.cursor),
+            Motion::Up => match self.vertical(false) {
+                Some(target) => target,
+                None => return,
+            },
+            Motion::Down => match self.vertical(true) {
+                Some(target) => target,
+                None => return,
+            },
+        };
+
+        if !select {
+            self.anchor = None;
+        } else if self.anchor.is_none() {
+            self.anchor = Some(self.cursor);
+        }
+
+        self.cursor = target;
+    }
+
+    // Start of the word before the cursor
+    fn word_left(&self) -> usize {
+        let chars = self.chars();
+        let mut index = self.cursor;
+
+        while index > 0 && chars[index - 1].is_whitespace() {
+            index -= 1;
+        }
+        while index > 0 && !chars[index - 1].is_whitespace() {
+            index -= 1;
+        }
+
+        index
+    }
+
+    // End of the word after the cursor
+    fn word_right(&self) -> usize {
+        let chars = self.chars();
+        let mut index = self.cursor;
+
+        while index < chars.len() && chars[index].is_whitespace() {
+            index += 1;
+        }
+        while index < chars.len() && !chars[index].is_whitespace() {
+            index += 1;
+        }
+
+        index
+    }
+
+    fn line_start(&self, index: usize) -> usize {
+        let chars = self.chars();
+        chars[..index].iter().rposition(|c| *c == '\n').map_or(0, |newline| newline + 1)
+    }
+
+    fn line_end(&self, index: usize) -> usize {
+        let chars = self.chars();
+        chars[index..].iter().position(|c| *c == '\n').map_or(chars.len(), |newline| index + newline)
+    }
+
+    // Same column on the line above or below, as far as that line allows
+    fn vertical(&self, down: bool) -> Option<usize> {
+        if !self.multiline {
+            return None;
+        }
+
+        let start = self.line_start(self.cursor);
+        let column = self.cursor - start;
+
+        if down {
+            let end = self.line_end(self.cursor);
+            if end == self.len() {
+                return None;
+            }
+            Some((end + 1 + column).min(self.line_end(end + 1)))
+        } else {
+            if start == 0 {
+                return None;
+            }
+            Some((self.line_start(start - 1) + column).min(start - 1))
+        }
+    }
+
+    // Characters with their positions, in rows of at most `width` columns,
+    // and the row and column of the cursor
+    fn rows(&self, width: usize) -> (Vec<Row>, (usize, usize)) {
+        let mut rows = vec![Vec::new()];
+        let mut column = 0;
+        let mut cursor = None;
+
+        for (index, c) in self.value.chars().enumerate() {
+            let char_width = c.width().unwrap_or(0);
+            if c != '\n' && column + char_width > width && column > 0 {
+                rows.push(Vec::new());
+                column = 0;
+            }
+
+            if index == self.cursor {
+                cursor = Some((rows.len() - 1, column));
+            }
+
+            if c == '\n' {
+                rows.push(Vec::new());
+                column = 0;
+            } else if let Some(row) = rows.last_mut() {
+                row.push((index, c));
+                column += char_width;
+            }
+        }
+
+        let cursor = cursor.unwrap_or((rows.len() - 1, column));
+        (rows, cursor)
+    }
+}
+
+// Draws a `TextInput`. Multi-line inputs wrap and scroll vertically,
+// single-line ones scroll sideways, both keeping the cursor in view.
+pub struct TextField<'a> {
+    input: &'a TextInput,
+    block: Option<Block<'a>>,
+    placeholder: &'a str,
+    focused: bool,
+    theme: Theme,
+}
+
+impl<'a> TextField<'a> {
+    pub fn new(input: &'a TextInput) -> Self {
+        Self {
+            input,
+            block: None,
+            placeholder: "",
+            focused: false,
+            theme: Theme::default(),
+        }
+    }
+
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    // Shown while the input is empty
+    pub fn placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+
+    // Only the focused field shows its cursor
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.theme = *theme;
+        self
+    }
+}
+
+impl Widget for TextField<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = match self.block {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+            None => area,
+        };
+
+        if area.is_empty() {
+            return;
+        }
+
+        let input = self.input;
+        let cursor_style = Style::default().add_modifier(Modifier::REVERSED);
+        let text_style = Style::default().fg(self.theme.text);
+
+        if input.value.is_empty() {
+            let placeholder = Style::default().fg(self.theme.faint).italic();
+            buf.set_stringn(area.x, area.y, self.placeholder, usize::from(area.width), placeholder);
+            if self.focused {
+                buf[(area.x, area.y)].set_style(cursor_style);
+            }
+            return;
+        }
+
+        let width = usize::from(area.width);
+        let height = usize::from(area.height);
+        let (rows, (mut cursor_row, mut cursor_column)) = input.rows(if input.multiline { width } else { usize::MAX });
+
+        // A cursor after a full row goes to the start of the next one
+        if input.multiline && cursor_column >= width {
+            cursor_row += 1;
+            cursor_column = 0;
+        }
+
+        let first_row = if input.multiline { (cursor_row + 1).saturating_sub(height) } else { 0 };
+        let first_column = if input.multiline { 0 } else { (cursor_column + 1).saturating_sub(width) };
+        let selection = input.selection().unwrap_or_default();
+
+        for (y, row) in rows.iter().skip(first_row).take(height).enumerate() {
+            let y = area.y + y as u16;
+            let mut column = 0;
+
+            for &(index, c) in row {
+                let char_width = c.width().unwrap_or(0);
+                if column >= first_column && column + char_width <= first_column + width {
+                    let style = if selection.contains(&index) { text_style.patch(cursor_style) } else { text_style };
+                    buf.set_string(area.x + (column - first_column) as u16, y, c.to_string(), style);
+                }
+                column += char_width;
+            }
+        }
+
+        if self.focused && cursor_row >= first_row && cursor_row - first_row < height {
+            let x = area.x + (cursor_column - first_column) as u16;
+            let y = area.y + (cursor_row - first_row) as u16;
+            if x < area.right() {
+                buf[(x, y)].set_style(cursor_style);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(key: &str) -> KeyPress {
+        KeyPress {
+            key: key.to_string(),
+            code: String::new(),
+            shift: false,
+            alt: false,
+            ctrl: false,
+            meta: false,
+        }
+    }
+
+    fn typed(input: TextInput, text: &str) -> TextInput {
+        let mut input = input;
+        input.apply(Edit::Insert(text.to_string()));
+        input
+    }
+
+    fn moved(input: &mut TextInput, motion: Motion) {
+        input.apply(Edit::Move(motion, false));
+    }
+
+    #[test]
+    fn keys_become_edits() {
+        assert_eq!(Edit::from_key(&press("x")), Some(Edit::Insert("x".to_string())));
+        assert_eq!(Edit::from_key(&press("Enter")), Some(Edit::Insert("\n".to_string())));
+        assert_eq!(Edit::from_key(&press("Backspace")), Some(Edit::DeleteBackward));
+        assert_eq!(Edit::from_key(&press("Shift")), None);
+        assert_eq!(Edit::from_key(&KeyPress { shift: true, ..press("ArrowLeft") }), Some(Edit::Move(Motion::Left, true)));
+    }
+
+    #[test]
+    fn word_and_line_keys() {
+        let ctrl = KeyPress { ctrl: true, ..press("ArrowLeft") };
+        let option = KeyPress { alt: true, ..press("ArrowRight") };
+        let command = KeyPress { ctrl: true, meta: true, ..press("ArrowLeft") };
+
+        assert_eq!(Edit::from_key(&ctrl), Some(Edit::Move(Motion::WordLeft, false)));
+        assert_eq!(Edit::from_key(&option), Some(Edit::Move(Motion::WordRight, false)));
+        assert_eq!(Edit::from_key(&command), Some(Edit::Move(Motion::Home, false)));
+        assert_eq!(Edit::from_key(&KeyPress { ctrl: true, ..press("a") }), Some(Edit::SelectAll));
+
+        // Left to the browser, for copy and paste
+        assert_eq!(Edit::from_key(&KeyPress { ctrl: true, ..press("c") }), None);
+    }
+
+    #[test]
+    fn cursor_moves_by_characters() {
+        let mut input = typed(TextInput::new(), "héllo");
+        assert_eq!(input.cursor, 5);
+
+        moved(&mut input, Motion::Left);
+        moved(&mut input, Motion::Left);
+        input.apply(Edit::Insert("_".to_string()));
+        assert_eq!(input.value(), "hél_lo");
+
+        moved(&mut input, Motion::Home);
+        assert_eq!(input.cursor, 0);
+        assert!(!input.apply(Edit::Move(Motion::Left, false)));
+
+        input.apply(Edit::DeleteForward);
+        assert_eq!(input.value(), "él_lo");
+    }
+
+    #[test]
+    fn selection_is_replaced_and_collapsed() {
+        let mut input = typed(TextInput::new(), "one two");
+        input.apply(Edit::Move(Motion::WordLeft, true));
+        assert_eq!(input.selection(), Some(4..7));
+
+        input.apply(Edit::Insert("three".to_string()));
+        assert_eq!(input.value(), "one three");
+        assert_eq!(input.selection(), None);
+
+        input.apply(Edit::SelectAll);
+        moved(&mut input, Motion::Left);
+        assert_eq!((input.cursor, input.selection()), (0, None));
+
+        input.apply(Edit::SelectAll);
+        input.apply(Edit::DeleteBackward);
+        assert_eq!(input.value(), "");
+    }
+
+    #[test]
+    fn max_len_cuts_off_input() {
+        let mut input = typed(TextInput::new().max_len(5), "abcdefgh");
+        assert_eq!(input.value(), "abcde");
+        assert!(!input.apply(Edit::Insert("x".to_string())));
+
+        // Room made by the selection counts
+        input.apply(Edit::Move(Motion::Left, true));
+        input.apply(Edit::Insert("xyz".to_string()));
+        assert_eq!(input.value(), "abcdx");
+    }
+
+    #[test]
+    fn single_line_turns_breaks_into_spaces() {
+        let input = typed(TextInput::new(), "one\r\ntwo\nthree");
+        assert_eq!(input.value(), "one two three");
+    }
+
+    #[test]
+    fn multiline_moves_between_lines() {
+        let mut input = typed(TextInput::new().multiline(true), "long line\nab\nlast line");
+
+        // Up keeps the column as far as the shorter line allows
+        moved(&mut input, Motion::Up);
+        assert_eq!(input.cursor, 12);
+        moved(&mut input, Motion::Up);
+        assert_eq!(input.cursor, 2);
+        assert!(!input.apply(Edit::Move(Motion::Up, false)));
+
+        moved(&mut input, Motion::End);
+        assert_eq!(input.cursor, 9);
+        moved(&mut input, Motion::Down);
+        assert_eq!(input.cursor, 12);
+
+        let mut single = typed(TextInput::new(), "one");
+        assert!(!single.apply(Edit::Move(Motion::Up, false)));
+    }
+
+    #[test]
+    fn words_are_split_by_whitespace() {
+        let mut input = typed(TextInput::new(), "  one,two  three ");
+
+        moved(&mut input, Motion::WordLeft);
+        assert_eq!(input.cursor, 11);
+        moved(&mut input, Motion::WordLeft);
+        assert_eq!(input.cursor, 2);
+        moved(&mut input, Motion::WordLeft);
+        assert_eq!(input.cursor, 0);
+
+        moved(&mut input, Motion::WordRight);
+        assert_eq!(input.cursor, 9);
+        moved(&mut input, Motion::WordRight);
+        assert_eq!(input.cursor, 16);
+
+        input.apply(Edit::DeleteWordBackward);
+        assert_eq!(input.value(), "  one,two   ");
+    }
+}
@@ -0,0 +1,192 @@
+use ratatui::style::Color;
+
+use crate::i18n::t;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ThemeName {
+    #[default]
+    Dark,
+    Light,
+    HighContrast,
+    ColorBlind,     // Okabe-Ito colors, told apart with any color vision
+}
+
+impl ThemeName {
+    // Order the toggle goes through
+    pub const ALL: [ThemeName; 4] = [ThemeName::Dark, ThemeName::Light, ThemeName::HighContrast, ThemeName::ColorBlind];
+
+    pub fn theme(self) -> &'static Theme {
+        match self {
+            ThemeName::Dark => &DARK,
+            ThemeName::Light => &LIGHT,
+            ThemeName::HighContrast => &HIGH_CONTRAST,
+            ThemeName::ColorBlind => &COLOR_BLIND,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ThemeName::Dark => t("theme.dark"),
+            ThemeName::Light => t("theme.light"),
+            ThemeName::HighContrast => t("theme.high_contrast"),
+            ThemeName::ColorBlind => t("theme.color_blind"),
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|name| *name == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    // How a chosen theme is stored in the preferences
+    pub fn key(self) -> &'static str {
+        match self {
+            ThemeName::Dark => "dark",
+            ThemeName::Light => "light",
+            ThemeName::HighContrast => "high-contrast",
+            ThemeName::ColorBlind => "color-blind",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|name| name.key() == key)
+    }
+
+    // What applies until the visitor picks a theme, from `prefers-contrast`
+    // and `prefers-color-scheme`
+    pub fn system() -> Self {
+        if matches_media(CONTRAST_QUERY) {
+            ThemeName::HighContrast
+        } else if matches_media(LIGHT_QUERY) {
+            ThemeName::Light
+        } else {
+            ThemeName::Dark
+        }
+    }
+}
+
+pub const CONTRAST_QUERY: &str = "(prefers-contrast: more)";
+pub const LIGHT_QUERY: &str = "(prefers-color-scheme: light)";
+
+// Colors by what they mean rather than how they look. Screens only use
+// these, so every palette applies everywhere.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub name: ThemeName,
+    pub background: Color,
+    pub text: Color,
+    pub title: Color,
+    pub accent: Color,      // Demo, menus, headings
+    pub highlight: Color,   // Blog, list markers
+    pub success: Color,     // Contact, confirmations
+    pub warning: Color,     // Key hints, countdowns
+    pub alert: Color,       // Alarms and errors
+    pub muted: Color,       // Secondary text
+    pub faint: Color,       // Hints, placeholders, disabled widgets
+    pub border: Color,
+    pub focus: Color,       // Border of the focused widget
+    pub link: Color,
+    pub code: Color,
+    pub css_background: &'static str,   // `background` for the page behind the terminal
+    pub css_text: &'static str,         // `color` for text on the page, like the error page
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        DARK
+    }
+}
+
+pub const DARK: Theme = Theme {
+    name: ThemeName::Dark,
+    background: Color::Rgb(18, 18, 18),
+    text: Color::White,
+    title: Color::LightYellow,
+    accent: Color::Cyan,
+    highlight: Color::Magenta,
+    success: Color::Green,
+    warning: Color::Yellow,
+    alert: Color::Red,
+    muted: Color::Gray,
+    faint: Color::DarkGray,
+    border: Color::Gray,
+    focus: Color::White,
+    link: Color::LightBlue,
+    code: Color::LightGreen,
+    css_background: "#121212",
+    css_text: "#ffffff",
+};
+
+pub const LIGHT: Theme = Theme {
+    name: ThemeName::Light,
+    background: Color::Rgb(250, 250, 247),
+    text: Color::Rgb(28, 28, 28),
+    title: Color::Rgb(150, 85, 0),
+    accent: Color::Rgb(0, 110, 140),
+    highlight: Color::Rgb(150, 30, 140),
+    success: Color::Rgb(20, 120, 40),
+    warning: Color::Rgb(160, 95, 0),
+    alert: Color::Rgb(190, 20, 20),
+    muted: Color::Rgb(85, 85, 85),
+    faint: Color::Rgb(140, 140, 140),
+    border: Color::Rgb(85, 85, 85),
+    focus: Color::Rgb(0, 0, 0),
+    link: Color::Rgb(0, 70, 180),
+    code: Color::Rgb(20, 110, 40),
+    css_background: "#fafaf7",
+    css_text: "#1c1c1c",
+};
+
+// Pure colors on black and no grey body text, for low vision
+pub const HIGH_CONTRAST: Theme = Theme {
+    name: ThemeName::HighContrast,
+    background: Color::Rgb(0, 0, 0),
+    text: Color::Rgb(255, 255, 255),
+    title: Color::Rgb(255, 255, 0),
+    accent: Color::Rgb(0, 255, 255),
+    highlight: Color::Rgb(255, 140, 255),
+    success: Color::Rgb(0, 255, 0),
+    warning: Color::Rgb(255, 255, 0),
+    alert: Color::Rgb(255, 90, 90),
+    muted: Color::Rgb(255, 255, 255),
+    faint: Color::Rgb(200, 200, 200),
+    border: Color::Rgb(255, 255, 255),
+    focus: Color::Rgb(255, 255, 0),
+    link: Color::Rgb(140, 210, 255),
+    code: Color::Rgb(0, 255, 0),
+    css_background: "#000000",
+    css_text: "#ffffff",
+};
+
+pub const COLOR_BLIND: Theme = Theme {
+    name: ThemeName::ColorBlind,
+    background: Color::Rgb(18, 18, 18),
+    text: Color::White,
+    title: Color::Rgb(240, 228, 66),
+    accent: Color::Rgb(86, 180, 233),
+    highlight: Color::Rgb(204, 121, 167),
+    success: Color::Rgb(0, 158, 115),
+    warning: Color::Rgb(230, 159, 0),
+    alert: Color::Rgb(213, 94, 0),
+    muted: Color::Gray,
+    faint: Color::DarkGray,
+    border: Color::Gray,
+    focus: Color::White,
+    link: Color::Rgb(86, 180, 233),
+    code: Color::Rgb(0, 158, 115),
+    css_background: "#121212",
+    css_text: "#ffffff",
+};
+
+#[cfg(not(feature = "native"))]
+fn matches_media(query: &str) -> bool {
+    web_sys::window()
+        .and_then(|window| window.match_media(query).ok().flatten())
+        .is_some_and(|list| list.matches())
+}
+
+// A terminal doesn't say, so the terminal build starts dark
+#[cfg(feature = "native")]
+fn matches_media(_query: &str) -> bool {
+    false
+}
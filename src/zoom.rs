@@ -0,0 +1,37 @@
+// Text sizes to step through, in percent of the page's 16px font
+const LEVELS: [u16; 6] = [100, 125, 150, 175, 200, 250];
+
+// How large the terminal's text is drawn, as an index into `LEVELS`.
+//
+// The backend lays its grid out for the 16px font whatever size the text
+// is, so larger text leaves room for fewer cells: the page draws into the
+// top-left part of the terminal (see `app::zoomed_area`) and scales the
+// font up until that part fills the window.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Zoom(usize);
+
+impl Zoom {
+    pub fn percent(self) -> u16 {
+        LEVELS[self.0]
+    }
+
+    pub fn from_percent(percent: u16) -> Option<Self> {
+        LEVELS.iter().position(|level| *level == percent).map(Zoom)
+    }
+
+    pub fn larger(self) -> Self {
+        if self.is_largest() { self } else { Zoom(self.0 + 1) }
+    }
+
+    pub fn smaller(self) -> Self {
+        if self.is_smallest() { self } else { Zoom(self.0 - 1) }
+    }
+
+    pub fn is_largest(self) -> bool {
+        self.0 == LEVELS.len() - 1
+    }
+
+    pub fn is_smallest(self) -> bool {
+        self.0 == 0
+    }
+}